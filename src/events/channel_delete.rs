@@ -2,13 +2,58 @@ use std::sync::Arc;
 
 use twilight_model::gateway::payload::incoming::ChannelDelete;
 
-use crate::types::{context::Context, Result};
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    database::IgnoredKind,
+    Result,
+};
 
-pub fn handle_channel_delete(
+pub async fn handle_channel_delete(
     context: Arc<Context>,
     payload: ChannelDelete,
 ) -> Result<()> {
-    context.cache.remove_channel(payload.0.id);
+    let channel_id = payload.0.id;
+
+    context.cache.remove_channel(channel_id);
+
+    let Some(guild_id) = payload.0.guild_id else {
+        return Ok(())
+    };
+
+    context
+        .database
+        .remove_ignored(guild_id, IgnoredKind::TextChannel, channel_id.get())
+        .await?;
+    context
+        .database
+        .remove_ignored(guild_id, IgnoredKind::VoiceChannel, channel_id.get())
+        .await?;
+    context
+        .database
+        .remove_channel_multiplier(guild_id, channel_id)
+        .await?;
+
+    let Some(guild) = context.cache.get_guild(guild_id) else {
+        return Ok(())
+    };
+    let mut channel_multipliers = guild.channel_multipliers.read().clone();
+    let mut ignored_text_channel_ids = guild.ignored_text_channel_ids.read().clone();
+    let mut ignored_voice_channel_ids = guild.ignored_voice_channel_ids.read().clone();
+
+    channel_multipliers.remove(&channel_id);
+    ignored_text_channel_ids.remove(&channel_id);
+    ignored_voice_channel_ids.remove(&channel_id);
+
+    context.cache.update_guild(
+        guild_id,
+        GuildUpdate {
+            channel_multipliers: Some(channel_multipliers),
+            ignored_text_channel_ids: Some(ignored_text_channel_ids),
+            ignored_voice_channel_ids: Some(ignored_voice_channel_ids),
+            ..Default::default()
+        },
+    );
 
     Ok(())
 }
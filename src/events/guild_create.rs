@@ -1,4 +1,7 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use time::OffsetDateTime;
 use twilight_model::{
@@ -10,13 +13,21 @@ use twilight_model::{
     }
 };
 
-use crate::types::{context::Context, Result};
+use crate::{
+    types::{
+        cache::{MemberUpdate, VoiceStatus},
+        context::Context,
+        Result,
+    },
+    utility::{image::member_avatar_url, voice::refresh_voice_channel},
+};
 
 pub async fn handle_guild_create(
     context: Arc<Context>,
     payload: GuildCreate,
 ) -> Result<()> {
     let TwilightGuild {
+        afk_channel_id,
         channels,
         id: guild_id,
         members: guild_members,
@@ -25,35 +36,32 @@ pub async fn handle_guild_create(
         voice_states,
         ..
     } = payload.0;
-    let guild_role_ids = roles
+    let role_names = roles
         .into_iter()
-        .map(|role| role.id)
-        .collect::<HashSet<Id<RoleMarker>>>();
+        .map(|role| (role.id, role.name))
+        .collect::<HashMap<Id<RoleMarker>, String>>();
 
     context
         .database
-        .update_guild_levels(guild_id, guild_role_ids)
+        .update_guild_levels(guild_id, role_names.keys().copied().collect::<HashSet<_>>())
         .await?;
 
+    let channel_multipliers = context.database.get_channel_multipliers(guild_id).await?;
+    let (ignored_role_ids, ignored_text_channel_ids, ignored_voice_channel_ids) =
+        context.database.get_ignored(guild_id).await?;
     let levels = context.database.get_levels(guild_id).await?;
     let database_members = context.database.get_members(guild_id).await?;
     let formatted_members = guild_members
         .into_iter()
         .map(|member| {
             let user_id = member.user.id;
-            let avatar_url = if let Some(member_avatar) = member.avatar {
-                format!("https://cdn.discordapp.com/guilds/{guild_id}/users/{user_id}/avatars/{member_avatar}.png")
-            } else if let Some(user_avatar) = member.user.avatar {
-                format!("https://cdn.discordapp.com/avatars/{user_id}/{user_avatar}.png")
-            } else {
-                let index = if member.user.discriminator == 0 {
-                    (user_id.get() >> 22) % 6
-                } else {
-                    (member.user.discriminator % 5) as u64
-                };
-                
-                format!("https://cdn.discordapp.com/embed/avatars/{index}.png")
-            };
+            let avatar_url = member_avatar_url(
+                guild_id,
+                user_id,
+                member.avatar,
+                member.user.avatar,
+                member.user.discriminator,
+            );
             let (xp, last_message_timestamp) = database_members
                 .iter()
                 .find(|(database_member_user_id, ..)| user_id.eq(database_member_user_id))
@@ -87,11 +95,50 @@ pub async fn handle_guild_create(
             Option<Id<ChannelMarker>>,
             i64
         )>>();
-    let xp_multiplier = context.database.insert_guild(guild_id).await?;
+    let role_multipliers = context.database.get_role_multipliers(guild_id).await?;
+    let settings = context.database.insert_guild(guild_id).await?;
 
-    context
-        .cache
-        .insert_guild(channels, guild_id, levels, formatted_members, name, xp_multiplier);
+    context.cache.insert_guild(
+        afk_channel_id,
+        channel_multipliers,
+        channels,
+        guild_id,
+        ignored_role_ids,
+        ignored_text_channel_ids,
+        ignored_voice_channel_ids,
+        levels,
+        formatted_members,
+        name,
+        role_multipliers,
+        role_names,
+        settings,
+    );
+
+    let Some(guild) = context.cache.get_guild(guild_id) else {
+        return Ok(())
+    };
+    let now = OffsetDateTime::now_utc();
+
+    for voice_state in voice_states.iter() {
+        context.cache.update_member(
+            guild_id,
+            voice_state.user_id,
+            MemberUpdate {
+                voice_status: Some(VoiceStatus {
+                    deafened: voice_state.deaf || voice_state.self_deaf,
+                    muted: voice_state.mute || voice_state.self_mute,
+                    streaming: voice_state.self_stream,
+                    suppressed: voice_state.suppress,
+                    video: voice_state.self_video,
+                }),
+                ..Default::default()
+            },
+        );
+    }
+
+    for channel_id in guild.channel_ids.read().iter() {
+        refresh_voice_channel(&context, &guild, *channel_id, now);
+    }
 
     Ok(())
 }
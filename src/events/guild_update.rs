@@ -1,20 +1,54 @@
 use std::sync::Arc;
 
+use time::OffsetDateTime;
 use twilight_model::gateway::payload::incoming::GuildUpdate;
 
-use crate::types::{cache, context::Context, Result};
+use crate::{
+    types::{cache, context::Context, Result},
+    utility::voice::{refresh_voice_channel, settle_voice_channel},
+};
 
-pub fn handle_guild_update(
+pub async fn handle_guild_update(
     context: Arc<Context>,
     payload: GuildUpdate,
 ) -> Result<()> {
+    let now = OffsetDateTime::now_utc();
+    let afk_channel_ids = match context.cache.get_guild(payload.id) {
+        Some(guild) => {
+            let previous_afk_channel_id = *guild.afk_channel_id.read();
+
+            if previous_afk_channel_id.ne(&payload.afk_channel_id) {
+                let afk_channel_ids = previous_afk_channel_id
+                    .into_iter()
+                    .chain(payload.afk_channel_id)
+                    .collect::<Vec<_>>();
+
+                for channel_id in afk_channel_ids.iter() {
+                    settle_voice_channel(&context, &guild, *channel_id, now).await;
+                }
+
+                afk_channel_ids
+            } else {
+                Vec::new()
+            }
+        }
+        None => Vec::new(),
+    };
+
     context.cache.update_guild(
         payload.id,
         cache::GuildUpdate {
+            afk_channel_id: Some(payload.afk_channel_id),
             name: Some(payload.name.clone()),
             ..Default::default()
         },
     );
 
+    if let Some(guild) = context.cache.get_guild(payload.id) {
+        for channel_id in afk_channel_ids {
+            refresh_voice_channel(&context, &guild, channel_id, now);
+        }
+    }
+
     Ok(())
 }
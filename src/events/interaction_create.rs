@@ -10,10 +10,14 @@ use twilight_util::builder::embed::EmbedBuilder;
 use crate::{
     interactions::{
         commands::{
+            compare::CompareCommand,
             config::ConfigCommand,
             latency::LatencyCommand,
             leaderboard::LeaderboardCommand,
             rank::RankCommand,
+            rank_card::RankCardCommand,
+            season::SeasonCommand,
+            xp::XpCommand,
         },
         components::{leaderboard::LeaderboardComponent, level_roles::LevelRolesComponent},
     },
@@ -36,6 +40,7 @@ pub async fn handle_interaction_create(
 ) -> Result<()> {
     let Interaction {
         app_permissions,
+        channel,
         data,
         guild_id,
         id,
@@ -104,6 +109,7 @@ pub async fn handle_interaction_create(
             let member = member.unwrap();
             let mut interaction = ApplicationCommandInteraction {
                 cached_guild,
+                channel_id: channel.map(|channel| channel.id),
                 context: interaction_context,
                 data,
                 shard_id,
@@ -113,10 +119,14 @@ pub async fn handle_interaction_create(
             let command_name = take(&mut interaction.data.name);
 
             match command_name.as_str() {
+                "compare" => CompareCommand::run(&context, &mut interaction).await?,
                 "config" => ConfigCommand::run(&context, &mut interaction).await?,
                 "latency" => LatencyCommand::run(&context, &interaction).await?,
-                "leaderboard" => LeaderboardCommand::run(&context, &interaction).await?,
+                "leaderboard" => LeaderboardCommand::run(&context, &mut interaction).await?,
                 "rank" => RankCommand::run(&context, &mut interaction).await?,
+                "rank-card" => RankCardCommand::run(&context, &mut interaction).await?,
+                "season" => SeasonCommand::run(&context, &mut interaction).await?,
+                "xp" => XpCommand::run(&context, &mut interaction).await?,
                 _ => {
                     interaction
                         .context
@@ -140,10 +150,12 @@ pub async fn handle_interaction_create(
                 data,
                 message: message.unwrap(),
                 shard_id,
+                user_id: member.unwrap().user.unwrap().id,
             };
 
-            match interaction.data.custom_id.as_str() {
-                "leaderboard-next" | "leaderboard-previous" => {
+            match interaction.data.custom_id.split(':').next().unwrap_or_default() {
+                "leaderboard-first" | "leaderboard-last" | "leaderboard-me" | "leaderboard-next"
+                | "leaderboard-previous" => {
                     LeaderboardComponent::run(&context, &interaction).await?
                 }
                 "level-roles-next" | "level-roles-previous" => {
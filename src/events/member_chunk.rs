@@ -1,4 +1,4 @@
-use std::{cmp, collections::HashSet, sync::Arc};
+use std::{collections::HashSet, sync::Arc};
 
 use twilight_model::{
     gateway::payload::incoming::MemberChunk,
@@ -7,7 +7,10 @@ use twilight_model::{
 
 use crate::{
     types::{context::Context, Result},
-    utility::constants::FLUCTUATING_XP,
+    utility::{
+        image::member_avatar_url,
+        leveling::{level_for_xp, member_role_ids},
+    },
 };
 
 pub async fn handle_member_chunk(
@@ -19,43 +22,31 @@ pub async fn handle_member_chunk(
         return Ok(());
     };
 
+    let level_curve = guild.level_curve.read().clone();
+
     for member in payload.members {
         let user_id = member.user.id;
 
-        let avatar_url = if let Some(member_avatar) = member.avatar {
-            format!("https://cdn.discordapp.com/guilds/{guild_id}/users/{user_id}/avatars/{member_avatar}.png")
-        } else if let Some(user_avatar) = member.user.avatar {
-            format!("https://cdn.discordapp.com/avatars/{user_id}/{user_avatar}.png")
-        } else {
-            let index = if member.user.discriminator == 0 {
-                (user_id.get() >> 22) % 6
-            } else {
-                (member.user.discriminator % 5) as u64
-            };
-
-            format!("https://cdn.discordapp.com/embed/avatars/{index}.png")
-        };
+        let avatar_url = member_avatar_url(
+            guild_id,
+            user_id,
+            member.avatar,
+            member.user.avatar,
+            member.user.discriminator,
+        );
 
         let (current_xp, last_message_timestamp) = context
             .database
             .get_member(guild_id, user_id)
             .await?
             .unwrap_or_default();
-        let current_level = FLUCTUATING_XP
-            .iter()
-            .position(|&level| current_xp.lt(&level.1))
-            .map_or(100, |position| FLUCTUATING_XP[cmp::max(position - 1, 0)].0);
-        let mut role_ids = HashSet::from_iter(member.roles.clone());
-        let level_role_ids = guild
-            .levels
-            .read()
-            .to_owned()
-            .into_iter()
-            .filter_map(|(level, role_ids)| level.le(&current_level).then(|| role_ids))
-            .flatten()
-            .collect::<HashSet<Id<RoleMarker>>>();
-
-        role_ids.extend(level_role_ids);
+        let current_level = level_for_xp(&level_curve, current_xp);
+        let role_ids = member_role_ids(
+            &HashSet::from_iter(member.roles.clone()),
+            &guild.levels.read(),
+            current_level,
+            *guild.role_stacking.read(),
+        );
 
         context
             .http
@@ -1,4 +1,4 @@
-use std::{cmp, collections::HashSet, sync::Arc};
+use std::sync::Arc;
 
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use time::{ext::NumericalDuration, OffsetDateTime};
@@ -8,8 +8,8 @@ use twilight_model::{
 };
 
 use crate::{
-    types::{cache::MemberUpdate, context::Context, Result},
-    utility::constants::FLUCTUATING_XP,
+    types::{cache::MemberUpdate, context::Context, database::XpSource, Result},
+    utility::{level_up::announce_level_up, leveling::{level_for_xp, member_role_ids}},
 };
 
 pub async fn handle_message_create(
@@ -34,8 +34,22 @@ pub async fn handle_message_create(
         return Ok(())
     };
 
+    if guild
+        .ignored_text_channel_ids
+        .read()
+        .contains(&payload.0.channel_id)
+        || !member
+            .role_ids
+            .read()
+            .is_disjoint(&guild.ignored_role_ids.read())
+    {
+        return Ok(());
+    }
+
     if let Some(last_message_timestamp) = member.last_message_timestamp.read().clone() {
-        let new_message_timestamp_threshold = last_message_timestamp.saturating_add(1.minutes());
+        let cooldown_seconds = *guild.cooldown_seconds.read();
+        let new_message_timestamp_threshold =
+            last_message_timestamp.saturating_add(cooldown_seconds.seconds());
 
         if new_message_timestamp_threshold.gt(&message_timestamp) {
             return Ok(());
@@ -43,44 +57,28 @@ pub async fn handle_message_create(
     }
 
     let mut rng: StdRng = SeedableRng::from_entropy();
-    let base_xp = rng.gen_range(35 ..= 45);
-    let xp_multiplier = *guild.xp_multiplier.read();
+    let min_xp = *guild.min_xp.read();
+    let max_xp = *guild.max_xp.read();
+    let base_xp = rng.gen_range(min_xp ..= max_xp);
+    let xp_multiplier =
+        guild.effective_xp_multiplier(payload.0.channel_id, &member.role_ids.read());
     let xp = ((base_xp as f64) * xp_multiplier).floor() as i64;
 
-    let current_xp = member.xp.read().to_owned();
-    let current_level = FLUCTUATING_XP
-        .iter()
-        .position(|&level| current_xp.lt(&level.1))
-        .map_or(100, |position| FLUCTUATING_XP[cmp::max(position - 1, 0)].0);
-    let updated_xp = current_xp + xp;
-    let updated_level = FLUCTUATING_XP
-        .iter()
-        .position(|&level| updated_xp.lt(&level.1))
-        .map_or(100, |position| FLUCTUATING_XP[cmp::max(position - 1, 0)].0);
-
-    if updated_level.ne(&current_level) {
-        let mut member_role_ids = member.role_ids.read().to_owned();
-        let level_role_ids = guild
-            .levels
-            .read()
-            .to_owned()
-            .into_iter()
-            .filter_map(|(level, role_ids)| level.le(&updated_level).then(|| role_ids))
-            .flatten()
-            .collect::<HashSet<Id<RoleMarker>>>();
-
-        member_role_ids.extend(level_role_ids);
-
-        context
-            .http
-            .update_guild_member(guild_id, user_id)
-            .roles(&member_role_ids.into_iter().collect::<Vec<Id<RoleMarker>>>())
-            .await?;
-    }
+    let updated_xp = context
+        .database
+        .increment_member_xp(guild_id, user_id, xp, Some(message_timestamp))
+        .await?;
+    let level_curve = guild.level_curve.read().clone();
+    let current_level = level_for_xp(&level_curve, updated_xp - xp);
+    let updated_level = level_for_xp(&level_curve, updated_xp);
 
     context
         .database
-        .update_member_xp(guild_id, user_id, updated_xp, Some(message_timestamp))
+        .insert_xp_history(guild_id, user_id, xp, XpSource::Message)
+        .await?;
+    context
+        .database
+        .insert_season_xp(guild_id, user_id, xp)
         .await?;
     context.cache.update_member(
         guild_id,
@@ -92,5 +90,29 @@ pub async fn handle_message_create(
         },
     );
 
+    if updated_level.ne(&current_level) {
+        let member_role_ids = member_role_ids(
+            &member.role_ids.read(),
+            &guild.levels.read(),
+            updated_level,
+            *guild.role_stacking.read(),
+        );
+
+        context
+            .http
+            .update_guild_member(guild_id, user_id)
+            .roles(&member_role_ids.into_iter().collect::<Vec<Id<RoleMarker>>>())
+            .await?;
+        announce_level_up(
+            &context,
+            &guild,
+            payload.0.channel_id,
+            user_id,
+            updated_level,
+            updated_xp,
+        )
+        .await?;
+    }
+
     Ok(())
 }
@@ -10,7 +10,9 @@ mod member_remove;
 mod member_update;
 mod message_create;
 mod ready;
+mod role_create;
 mod role_delete;
+mod role_update;
 mod unavailable_guild;
 mod voice_state_update;
 
@@ -32,7 +34,9 @@ use self::{
     member_update::handle_member_update,
     message_create::handle_message_create,
     ready::handle_ready,
+    role_create::handle_role_create,
     role_delete::handle_role_delete,
+    role_update::handle_role_update,
     unavailable_guild::handle_unavailable_guild,
     voice_state_update::handle_voice_state_update,
 };
@@ -46,14 +50,14 @@ pub async fn handle_event(
 ) -> Result<()> {
     match event {
         Event::ChannelCreate(payload) => handle_channel_create(context, *payload),
-        Event::ChannelDelete(payload) => handle_channel_delete(context, *payload),
+        Event::ChannelDelete(payload) => handle_channel_delete(context, *payload).await,
         Event::GuildCreate(payload) => {
             shard_sender.command(&RequestGuildMembers::builder(payload.0.id).query("", None))?;
 
             handle_guild_create(context, *payload).await
         }
         Event::GuildDelete(payload) => handle_guild_delete(context, payload).await,
-        Event::GuildUpdate(payload) => handle_guild_update(context, *payload),
+        Event::GuildUpdate(payload) => handle_guild_update(context, *payload).await,
         Event::InteractionCreate(payload) => {
             handle_interaction_create(context, shard_id, *payload).await
         }
@@ -63,7 +67,9 @@ pub async fn handle_event(
         Event::MemberUpdate(payload) => handle_member_update(context, *payload).await,
         Event::MessageCreate(payload) => handle_message_create(context, *payload).await,
         Event::Ready(payload) => handle_ready(context, *payload),
+        Event::RoleCreate(payload) => handle_role_create(context, payload),
         Event::RoleDelete(payload) => handle_role_delete(context, payload).await,
+        Event::RoleUpdate(payload) => handle_role_update(context, payload),
         Event::UnavailableGuild(payload) => handle_unavailable_guild(context, payload),
         Event::VoiceStateUpdate(payload) => handle_voice_state_update(context, *payload).await,
         _ => Ok(()),
@@ -2,7 +2,12 @@ use std::{collections::HashSet, iter, sync::Arc};
 
 use twilight_model::gateway::payload::incoming::RoleDelete;
 
-use crate::types::{context::Context, Result};
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    database::IgnoredKind,
+    Result,
+};
 
 pub async fn handle_role_delete(
     context: Arc<Context>,
@@ -15,6 +20,35 @@ pub async fn handle_role_delete(
         .database
         .update_guild_levels(guild_id, HashSet::from_iter(iter::once(role_id)))
         .await?;
+    context
+        .database
+        .remove_ignored(guild_id, IgnoredKind::Role, role_id.get())
+        .await?;
+    context
+        .database
+        .remove_role_multiplier(guild_id, role_id)
+        .await?;
+
+    let Some(guild) = context.cache.get_guild(guild_id) else {
+        return Ok(())
+    };
+    let mut ignored_role_ids = guild.ignored_role_ids.read().clone();
+    let mut role_multipliers = guild.role_multipliers.read().clone();
+    let mut role_names = guild.role_names.read().clone();
+
+    ignored_role_ids.remove(&role_id);
+    role_multipliers.remove(&role_id);
+    role_names.remove(&role_id);
+
+    context.cache.update_guild(
+        guild_id,
+        GuildUpdate {
+            ignored_role_ids: Some(ignored_role_ids),
+            role_multipliers: Some(role_multipliers),
+            role_names: Some(role_names),
+            ..Default::default()
+        },
+    );
 
     Ok(())
 }
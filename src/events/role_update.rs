@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use twilight_model::gateway::payload::incoming::RoleUpdate;
+
+use crate::types::{cache::GuildUpdate, context::Context, Result};
+
+pub fn handle_role_update(
+    context: Arc<Context>,
+    payload: RoleUpdate,
+) -> Result<()> {
+    let Some(guild) = context.cache.get_guild(payload.guild_id) else {
+        return Ok(())
+    };
+    let mut role_names = guild.role_names.read().clone();
+
+    role_names.insert(payload.role.id, payload.role.name);
+
+    context.cache.update_guild(
+        payload.guild_id,
+        GuildUpdate {
+            role_names: Some(role_names),
+            ..Default::default()
+        },
+    );
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    interactions::commands::rank::{get_member_details, get_member_rank, get_ranked_members},
+    types::{
+        cache::{Guild, Member},
+        context::Context,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::image::{default_avatar_url, get_comparison_image, ComparisonMember},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Compare two members' progress", name = "compare")]
+pub struct CompareCommand {
+    #[command(desc = "The first member", rename = "member_a")]
+    user_id_a: Id<UserMarker>,
+    #[command(desc = "The second member", rename = "member_b")]
+    user_id_b: Id<UserMarker>,
+}
+
+impl CompareCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            user_id_a,
+            user_id_b,
+        } = CompareCommand::from_interaction(interaction.input_data())?;
+
+        if user_id_a.eq(&user_id_b) {
+            let embed = EmbedBuilder::new()
+                .color(0xF8F8FF)
+                .description("Choose two different members to compare.")
+                .build();
+
+            interaction
+                .context
+                .update_response(UpdatePayload {
+                    embeds: vec![embed],
+                    ..Default::default()
+                })
+                .await?;
+
+            return Ok(());
+        }
+
+        let guild_id = interaction.cached_guild.guild_id;
+        let member_details_a = get_member_details(context, guild_id, user_id_a).await?;
+        let member_details_b = get_member_details(context, guild_id, user_id_b).await?;
+        let leaderboard = get_ranked_members(context, &interaction.cached_guild);
+        let members = [
+            get_comparison_member(
+                context,
+                &interaction.cached_guild,
+                &leaderboard,
+                user_id_a,
+                member_details_a,
+            )
+            .await,
+            get_comparison_member(
+                context,
+                &interaction.cached_guild,
+                &leaderboard,
+                user_id_b,
+                member_details_b,
+            )
+            .await,
+        ];
+        let level_curve = interaction.cached_guild.level_curve.read().clone();
+        let theme = context
+            .database
+            .get_rank_card_theme(guild_id, None)
+            .await?;
+        let background_image = context.renderer.background(guild_id, &theme).await?;
+        let attachment = context
+            .renderer
+            .render(move || get_comparison_image(background_image, level_curve, &theme, members))
+            .await?;
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                attachments: vec![attachment],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+async fn get_comparison_member(
+    context: &Context,
+    guild: &Guild,
+    leaderboard: &[Arc<Member>],
+    user_id: Id<UserMarker>,
+    (avatar_url, discriminator, username, xp): (String, u16, String, i64),
+) -> ComparisonMember {
+    let avatar_image = context
+        .renderer
+        .avatar(
+            &context.hyper,
+            &format!("{avatar_url}?size=256"),
+            &default_avatar_url(user_id, discriminator),
+        )
+        .await;
+
+    ComparisonMember {
+        avatar_image,
+        rank: get_member_rank(guild, leaderboard, user_id),
+        username,
+        xp,
+    }
+}
@@ -0,0 +1,123 @@
+use serde_json::json;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::http::attachment::Attachment;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::leveling::level_for_xp,
+};
+
+#[derive(CommandOption, CreateOption)]
+pub enum ConfigExportFormatOption {
+    #[option(name = "CSV", value = "csv")]
+    Csv,
+    #[option(name = "JSON", value = "json")]
+    Json,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Export the experience of every member", name = "export")]
+pub struct ConfigExportCommand {
+    #[command(desc = "The file format")]
+    format: ConfigExportFormatOption,
+}
+
+impl ConfigExportCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let guild_id = interaction.cached_guild.guild_id;
+        let level_curve = interaction.cached_guild.level_curve.read().clone();
+        let mut members = context.database.get_members(guild_id).await?;
+
+        members.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let rows = members
+            .into_iter()
+            .map(|(user_id, xp, last_message_timestamp)| {
+                let username = context
+                    .cache
+                    .get_member(guild_id, user_id)
+                    .map_or_else(String::new, |member| member.username.clone());
+
+                (
+                    user_id,
+                    username,
+                    xp,
+                    level_for_xp(&level_curve, xp),
+                    last_message_timestamp.map(|timestamp| timestamp.unix_timestamp()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let (filename, bytes) = match options.format {
+            ConfigExportFormatOption::Csv => {
+                let mut csv = "user_id,username,xp,level,last_message_timestamp\n".to_owned();
+
+                for (user_id, username, xp, level, last_message_timestamp) in rows.iter() {
+                    csv.push_str(&format!(
+                        "{user_id},{},{xp},{level},{}\n",
+                        escape_csv_field(username),
+                        last_message_timestamp
+                            .map(|timestamp| timestamp.to_string())
+                            .unwrap_or_default()
+                    ));
+                }
+
+                ("members.csv", csv.into_bytes())
+            }
+            ConfigExportFormatOption::Json => {
+                let json = rows
+                    .iter()
+                    .map(|(user_id, username, xp, level, last_message_timestamp)| {
+                        json!({
+                            "user_id": user_id.to_string(),
+                            "username": username,
+                            "xp": xp,
+                            "level": level,
+                            "last_message_timestamp": last_message_timestamp,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                ("members.json", serde_json::to_vec_pretty(&json)?)
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!("Exported the experience of {} member(s).", rows.len()))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                attachments: vec![Attachment::from_bytes(filename.to_owned(), bytes, 1)],
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
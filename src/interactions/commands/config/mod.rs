@@ -1,35 +1,105 @@
+mod add_ignored;
 mod add_level_role;
+mod export;
+mod remove_ignored;
 mod remove_level_role;
+mod reset_rank_background;
+mod set_channel_multiplier;
+mod set_cooldown;
+mod set_level_curve;
+mod set_level_up;
+mod set_multiplier_mode;
+mod set_rank_background;
+mod set_role_multiplier;
+mod set_role_stacking;
+mod set_voice_bonus;
+mod set_voice_rules;
+mod set_voice_xp;
 mod set_xp_multiplier;
+mod set_xp_range;
+mod sync_roles;
+mod view_ignored;
 mod view_level_roles;
+mod view_multipliers;
 
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::guild::Permissions;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use self::{
+    add_ignored::ConfigAddIgnoredCommand,
     add_level_role::ConfigAddLevelRoleCommand,
+    export::ConfigExportCommand,
+    remove_ignored::ConfigRemoveIgnoredCommand,
     remove_level_role::ConfigRemoveLevelRoleCommand,
+    reset_rank_background::ConfigResetRankBackgroundCommand,
+    set_channel_multiplier::ConfigSetChannelMultiplierCommand,
+    set_cooldown::ConfigSetCooldownCommand,
+    set_level_curve::ConfigSetLevelCurveCommand,
+    set_level_up::ConfigSetLevelUpCommand,
+    set_multiplier_mode::ConfigSetMultiplierModeCommand,
+    set_rank_background::ConfigSetRankBackgroundCommand,
+    set_role_multiplier::ConfigSetRoleMultiplierCommand,
+    set_role_stacking::ConfigSetRoleStackingCommand,
+    set_voice_bonus::ConfigSetVoiceBonusCommand,
+    set_voice_rules::ConfigSetVoiceRulesCommand,
+    set_voice_xp::ConfigSetVoiceXpCommand,
     set_xp_multiplier::ConfigSetXpMultiplierCommand,
+    set_xp_range::ConfigSetXpRangeCommand,
+    sync_roles::ConfigSyncRolesCommand,
+    view_ignored::ConfigViewIgnoredCommand,
     view_level_roles::ConfigViewLevelRolesCommand,
+    view_multipliers::ConfigViewMultipliersCommand,
 };
-use crate::types::{
-    context::Context,
-    interaction::{ApplicationCommandInteraction, ResponsePayload},
-    Result,
-};
+use crate::types::{context::Context, interaction::ApplicationCommandInteraction, Result};
 
 #[derive(CommandModel, CreateCommand)]
 #[command(desc = "Manage configuration", name = "config")]
 pub enum ConfigCommand {
+    #[command(name = "add-ignored")]
+    AddIgnored(ConfigAddIgnoredCommand),
     #[command(name = "add-level-role")]
     AddLevelRole(ConfigAddLevelRoleCommand),
+    #[command(name = "export")]
+    Export(ConfigExportCommand),
+    #[command(name = "remove-ignored")]
+    RemoveIgnored(ConfigRemoveIgnoredCommand),
     #[command(name = "remove-level-role")]
     RemoveLevelRole(ConfigRemoveLevelRoleCommand),
+    #[command(name = "reset-rank-background")]
+    ResetRankBackground(ConfigResetRankBackgroundCommand),
+    #[command(name = "set-channel-multiplier")]
+    SetChannelMultiplier(ConfigSetChannelMultiplierCommand),
+    #[command(name = "set-cooldown")]
+    SetCooldown(ConfigSetCooldownCommand),
+    #[command(name = "set-level-curve")]
+    SetLevelCurve(ConfigSetLevelCurveCommand),
+    #[command(name = "set-level-up")]
+    SetLevelUp(ConfigSetLevelUpCommand),
+    #[command(name = "set-multiplier-mode")]
+    SetMultiplierMode(ConfigSetMultiplierModeCommand),
+    #[command(name = "set-rank-background")]
+    SetRankBackground(ConfigSetRankBackgroundCommand),
+    #[command(name = "set-role-multiplier")]
+    SetRoleMultiplier(ConfigSetRoleMultiplierCommand),
+    #[command(name = "set-role-stacking")]
+    SetRoleStacking(ConfigSetRoleStackingCommand),
+    #[command(name = "set-voice-bonus")]
+    SetVoiceBonus(ConfigSetVoiceBonusCommand),
+    #[command(name = "set-voice-rules")]
+    SetVoiceRules(ConfigSetVoiceRulesCommand),
+    #[command(name = "set-voice-xp")]
+    SetVoiceXp(ConfigSetVoiceXpCommand),
     #[command(name = "set-xp-multiplier")]
     SetXpMultiplier(ConfigSetXpMultiplierCommand),
+    #[command(name = "set-xp-range")]
+    SetXpRange(ConfigSetXpRangeCommand),
+    #[command(name = "sync-roles")]
+    SyncRoles(ConfigSyncRolesCommand),
+    #[command(name = "view-ignored")]
+    ViewIgnored(ConfigViewIgnoredCommand),
     #[command(name = "view-level-roles")]
     ViewLevelRoles(ConfigViewLevelRolesCommand),
+    #[command(name = "view-multipliers")]
+    ViewMultipliers(ConfigViewMultipliersCommand),
 }
 
 impl ConfigCommand {
@@ -37,39 +107,77 @@ impl ConfigCommand {
         context: &Context,
         interaction: &mut ApplicationCommandInteraction<'_>,
     ) -> Result<()> {
-        if !interaction.user_permissions.map_or(false, |permissions| {
-            permissions.contains(Permissions::ADMINISTRATOR)
-        }) {
-            let embed = EmbedBuilder::new()
-                .color(0xF8F8FF)
-                .description(
-                    "You must be have administrator permissions in order to use this command."
-                        .to_owned(),
-                )
-                .build();
-
-            interaction
-                .context
-                .respond(ResponsePayload {
-                    embeds: vec![embed],
-                    ephemeral: true,
-                    ..Default::default()
-                })
-                .await?;
-        } else {
+        if interaction.require_administrator("use this command").await? {
             match ConfigCommand::from_interaction(interaction.input_data())? {
+                ConfigCommand::AddIgnored(options) => {
+                    ConfigAddIgnoredCommand::run(context, interaction, options).await?
+                }
                 ConfigCommand::AddLevelRole(options) => {
                     ConfigAddLevelRoleCommand::run(context, interaction, options).await?
                 }
+                ConfigCommand::Export(options) => {
+                    ConfigExportCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::RemoveIgnored(options) => {
+                    ConfigRemoveIgnoredCommand::run(context, interaction, options).await?
+                }
                 ConfigCommand::RemoveLevelRole(options) => {
                     ConfigRemoveLevelRoleCommand::run(context, interaction, options).await?
                 }
+                ConfigCommand::ResetRankBackground(_) => {
+                    ConfigResetRankBackgroundCommand::run(context, interaction).await?
+                }
+                ConfigCommand::SetChannelMultiplier(options) => {
+                    ConfigSetChannelMultiplierCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetCooldown(options) => {
+                    ConfigSetCooldownCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetLevelCurve(options) => {
+                    ConfigSetLevelCurveCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetLevelUp(options) => {
+                    ConfigSetLevelUpCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetMultiplierMode(options) => {
+                    ConfigSetMultiplierModeCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetRankBackground(options) => {
+                    ConfigSetRankBackgroundCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetRoleMultiplier(options) => {
+                    ConfigSetRoleMultiplierCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetRoleStacking(options) => {
+                    ConfigSetRoleStackingCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetVoiceBonus(options) => {
+                    ConfigSetVoiceBonusCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetVoiceRules(options) => {
+                    ConfigSetVoiceRulesCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SetVoiceXp(options) => {
+                    ConfigSetVoiceXpCommand::run(context, interaction, options).await?
+                }
                 ConfigCommand::SetXpMultiplier(options) => {
                     ConfigSetXpMultiplierCommand::run(context, interaction, options).await?
                 }
+                ConfigCommand::SetXpRange(options) => {
+                    ConfigSetXpRangeCommand::run(context, interaction, options).await?
+                }
+                ConfigCommand::SyncRoles(_) => {
+                    ConfigSyncRolesCommand::run(context, interaction).await?
+                }
+                ConfigCommand::ViewIgnored(_) => {
+                    ConfigViewIgnoredCommand::run(context, interaction).await?
+                }
                 ConfigCommand::ViewLevelRoles(_) => {
                     ConfigViewLevelRolesCommand::run(context, interaction).await?
                 }
+                ConfigCommand::ViewMultipliers(_) => {
+                    ConfigViewMultipliersCommand::run(context, interaction).await?
+                }
             }
         }
 
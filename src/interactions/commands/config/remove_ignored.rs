@@ -0,0 +1,156 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{
+    marker::{ChannelMarker, RoleMarker},
+    Id,
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    database::IgnoredKind,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Allow an ignored role, text channel or voice channel to earn XP again",
+    name = "remove-ignored"
+)]
+pub struct ConfigRemoveIgnoredCommand {
+    #[command(desc = "The role to stop ignoring", rename = "role")]
+    role_id: Option<Id<RoleMarker>>,
+    #[command(
+        channel_types = "guild_text guild_announcement guild_voice guild_stage_voice",
+        desc = "The text channel to stop ignoring",
+        rename = "text-channel"
+    )]
+    text_channel_id: Option<Id<ChannelMarker>>,
+    #[command(
+        channel_types = "guild_voice guild_stage_voice",
+        desc = "The voice channel to stop ignoring",
+        rename = "voice-channel"
+    )]
+    voice_channel_id: Option<Id<ChannelMarker>>,
+}
+
+impl ConfigRemoveIgnoredCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            role_id,
+            text_channel_id,
+            voice_channel_id,
+        } = options;
+        let guild_id = interaction.cached_guild.guild_id;
+        let mut descriptions: Vec<String> = Vec::new();
+
+        if let Some(role_id) = role_id {
+            let mut ignored_role_ids = interaction.cached_guild.ignored_role_ids.read().clone();
+
+            if ignored_role_ids.remove(&role_id) {
+                context
+                    .database
+                    .remove_ignored(guild_id, IgnoredKind::Role, role_id.get())
+                    .await?;
+                context.cache.update_guild(
+                    guild_id,
+                    GuildUpdate {
+                        ignored_role_ids: Some(ignored_role_ids),
+                        ..Default::default()
+                    },
+                );
+
+                descriptions.push(format!("Members with <@&{role_id}> can earn XP again."));
+            } else {
+                descriptions.push(format!("<@&{role_id}> is not ignored."));
+            }
+        }
+
+        if let Some(text_channel_id) = text_channel_id {
+            let mut ignored_text_channel_ids = interaction
+                .cached_guild
+                .ignored_text_channel_ids
+                .read()
+                .clone();
+
+            if ignored_text_channel_ids.remove(&text_channel_id) {
+                context
+                    .database
+                    .remove_ignored(guild_id, IgnoredKind::TextChannel, text_channel_id.get())
+                    .await?;
+                context.cache.update_guild(
+                    guild_id,
+                    GuildUpdate {
+                        ignored_text_channel_ids: Some(ignored_text_channel_ids),
+                        ..Default::default()
+                    },
+                );
+
+                descriptions.push(format!(
+                    "Messages in <#{text_channel_id}> can earn XP again."
+                ));
+            } else {
+                descriptions.push(format!("<#{text_channel_id}> is not ignored for messages."));
+            }
+        }
+
+        if let Some(voice_channel_id) = voice_channel_id {
+            let mut ignored_voice_channel_ids = interaction
+                .cached_guild
+                .ignored_voice_channel_ids
+                .read()
+                .clone();
+
+            if ignored_voice_channel_ids.remove(&voice_channel_id) {
+                context
+                    .database
+                    .remove_ignored(guild_id, IgnoredKind::VoiceChannel, voice_channel_id.get())
+                    .await?;
+                context.cache.update_guild(
+                    guild_id,
+                    GuildUpdate {
+                        ignored_voice_channel_ids: Some(ignored_voice_channel_ids),
+                        ..Default::default()
+                    },
+                );
+
+                descriptions.push(format!(
+                    "Time spent in <#{voice_channel_id}> can earn XP again."
+                ));
+            } else {
+                descriptions.push(format!("<#{voice_channel_id}> is not ignored for voice."));
+            }
+        }
+
+        if descriptions.is_empty() {
+            descriptions.push("Please provide a role, text channel or voice channel.".to_owned());
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(descriptions.join("\n"))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
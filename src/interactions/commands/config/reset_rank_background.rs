@@ -0,0 +1,49 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Reset the server's rank card background to the default",
+    name = "reset-rank-background"
+)]
+pub struct ConfigResetRankBackgroundCommand {}
+
+impl ConfigResetRankBackgroundCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        context
+            .database
+            .delete_rank_card_background(interaction.cached_guild.guild_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description("The rank card background has been reset to the default.")
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the experience multiplier for a channel (1 removes it)",
+    name = "set-channel-multiplier"
+)]
+pub struct ConfigSetChannelMultiplierCommand {
+    #[command(
+        channel_types = "guild_text guild_announcement guild_voice guild_stage_voice",
+        desc = "The channel",
+        rename = "channel"
+    )]
+    channel_id: Id<ChannelMarker>,
+    #[command(desc = "The multiplier", max_value = 5f64, min_value = 0.1f64)]
+    multiplier: f64,
+}
+
+impl ConfigSetChannelMultiplierCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            channel_id,
+            multiplier,
+        } = options;
+        let guild_id = interaction.cached_guild.guild_id;
+        let channel_multiplier = f64::trunc(multiplier * 10.0) / 10.0;
+        let mut channel_multipliers = interaction.cached_guild.channel_multipliers.read().clone();
+        let description = if channel_multiplier.eq(&1.0) {
+            context
+                .database
+                .remove_channel_multiplier(guild_id, channel_id)
+                .await?;
+
+            channel_multipliers.remove(&channel_id);
+
+            format!("<#{channel_id}> no longer has an XP multiplier.")
+        } else {
+            context
+                .database
+                .insert_channel_multiplier(guild_id, channel_id, channel_multiplier)
+                .await?;
+
+            channel_multipliers.insert(channel_id, channel_multiplier);
+
+            format!("The XP multiplier in <#{channel_id}> is now {channel_multiplier}x.")
+        };
+
+        context.cache.update_guild(
+            guild_id,
+            GuildUpdate {
+                channel_multipliers: Some(channel_multipliers),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the cooldown between messages that award experience",
+    name = "set-cooldown"
+)]
+pub struct ConfigSetCooldownCommand {
+    #[command(desc = "The cooldown in seconds", max_value = 3600, min_value = 0)]
+    seconds: i64,
+}
+
+impl ConfigSetCooldownCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            seconds,
+        } = options;
+
+        context
+            .database
+            .update_cooldown(interaction.cached_guild.guild_id, seconds)
+            .await?;
+        context.cache.update_guild(
+            interaction.cached_guild.guild_id,
+            GuildUpdate {
+                cooldown_seconds: Some(seconds),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Members can now earn message XP once every {seconds} second(s)."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
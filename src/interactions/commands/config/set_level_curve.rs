@@ -0,0 +1,182 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::channel::Attachment;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::GuildUpdate,
+        context::Context,
+        database::LevelCurve,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::constants::MAX_LEVEL,
+};
+
+#[derive(CommandOption, CreateOption)]
+pub enum ConfigLevelCurveOption {
+    #[option(name = "Custom (uploaded table)", value = "custom")]
+    Custom,
+    #[option(name = "Erratic", value = "erratic")]
+    Erratic,
+    #[option(name = "Exponential", value = "exponential")]
+    Exponential,
+    #[option(name = "Fluctuating", value = "fluctuating")]
+    Fluctuating,
+    #[option(name = "Linear", value = "linear")]
+    Linear,
+    #[option(name = "Medium fast", value = "medium_fast")]
+    MediumFast,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Set the level curve for the guild", name = "set-level-curve")]
+pub struct ConfigSetLevelCurveCommand {
+    #[command(desc = "The level curve")]
+    curve: ConfigLevelCurveOption,
+    #[command(
+        desc = "XP per level (linear) or XP for level 2 (exponential)",
+        max_value = 1_000_000f64,
+        min_value = 1f64
+    )]
+    base: Option<f64>,
+    #[command(
+        desc = "The growth ratio between levels (exponential)",
+        max_value = 2f64,
+        min_value = 1.01f64
+    )]
+    growth: Option<f64>,
+    #[command(desc = "A list of total XP per level, starting at level 1 (custom)")]
+    table: Option<Attachment>,
+}
+
+impl ConfigSetLevelCurveCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            base,
+            curve,
+            growth,
+            table,
+        } = options;
+        let level_curve = match curve {
+            ConfigLevelCurveOption::Custom => match table {
+                None => Err("Please upload a table of total XP per level.".to_owned()),
+                Some(attachment) if attachment.size > 1_000_000 => {
+                    Err("The uploaded table must be smaller than 1 MB.".to_owned())
+                }
+                Some(attachment) => {
+                    let response = context.hyper.get(attachment.url.parse()?).await?;
+                    let bytes = hyper::body::to_bytes(response.into_body()).await?;
+
+                    parse_level_table(&bytes).map(LevelCurve::Custom)
+                }
+            },
+            ConfigLevelCurveOption::Erratic => Ok(LevelCurve::Erratic),
+            ConfigLevelCurveOption::Exponential => Ok(LevelCurve::Exponential {
+                base: base.unwrap_or(100.0).trunc(),
+                growth: growth.unwrap_or(1.1),
+            }),
+            ConfigLevelCurveOption::Fluctuating => Ok(LevelCurve::Fluctuating),
+            ConfigLevelCurveOption::Linear => Ok(LevelCurve::Linear {
+                step: base.unwrap_or(100.0) as i64,
+            }),
+            ConfigLevelCurveOption::MediumFast => Ok(LevelCurve::MediumFast),
+        };
+        let description = match level_curve {
+            Err(description) => description,
+            Ok(level_curve) => {
+                context
+                    .database
+                    .update_level_curve(interaction.cached_guild.guild_id, &level_curve)
+                    .await?;
+
+                let description = match &level_curve {
+                    LevelCurve::Custom(table) => {
+                        format!("Levels now follow the uploaded table of {} levels.", table.len())
+                    }
+                    LevelCurve::Erratic => "Levels now follow the erratic curve.".to_owned(),
+                    LevelCurve::Exponential {
+                        base,
+                        growth,
+                    } => format!(
+                        "Levels now follow an exponential curve starting at {base} XP and growing by {growth}x per level."
+                    ),
+                    LevelCurve::Fluctuating => {
+                        "Levels now follow the fluctuating curve.".to_owned()
+                    }
+                    LevelCurve::Linear {
+                        step,
+                    } => format!("Levels now follow a linear curve of {step} XP per level."),
+                    LevelCurve::MediumFast => "Levels now follow the medium fast curve.".to_owned(),
+                };
+
+                context.cache.update_guild(
+                    interaction.cached_guild.guild_id,
+                    GuildUpdate {
+                        level_curve: Some(level_curve),
+                        ..Default::default()
+                    },
+                );
+
+                description
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn parse_level_table(bytes: &[u8]) -> std::result::Result<Vec<i64>, String> {
+    let mut table = match serde_json::from_slice::<Vec<i64>>(bytes) {
+        Ok(table) => table,
+        Err(_) => String::from_utf8_lossy(bytes)
+            .split(|character: char| character.eq(&',') || character.is_whitespace())
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse::<i64>())
+            .collect::<std::result::Result<Vec<i64>, _>>()
+            .map_err(|_| "The uploaded table must only contain whole numbers.".to_owned())?,
+    };
+
+    if table.first().map_or(true, |xp| xp.ne(&0)) {
+        table.insert(0, 0);
+    }
+
+    if table.len() < 2 {
+        return Err("The uploaded table must contain at least one level.".to_owned());
+    }
+
+    if table.len() as u64 > MAX_LEVEL {
+        return Err(format!(
+            "The uploaded table cannot contain more than {MAX_LEVEL} levels."
+        ));
+    }
+
+    if table.windows(2).any(|pair| pair[0].ge(&pair[1])) {
+        return Err("The uploaded table must be strictly increasing.".to_owned());
+    }
+
+    Ok(table)
+}
@@ -0,0 +1,134 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    database::LevelUpMode,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandOption, CreateOption)]
+pub enum ConfigLevelUpModeOption {
+    #[option(name = "A fixed channel", value = "channel")]
+    Channel,
+    #[option(name = "The channel the member earned XP in", value = "current")]
+    Current,
+    #[option(name = "A direct message", value = "direct")]
+    Direct,
+    #[option(name = "Off", value = "off")]
+    Off,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Set where and how level ups are announced", name = "set-level-up")]
+pub struct ConfigSetLevelUpCommand {
+    #[command(desc = "Where to announce level ups")]
+    mode: ConfigLevelUpModeOption,
+    #[command(
+        channel_types = "guild_text guild_announcement",
+        desc = "The channel to announce level ups in",
+        rename = "channel"
+    )]
+    channel_id: Option<Id<ChannelMarker>>,
+    #[command(
+        desc = "The message, with {user}, {level}, {xp} and {next_role} placeholders",
+        max_length = 1500
+    )]
+    template: Option<String>,
+}
+
+impl ConfigSetLevelUpCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            channel_id,
+            mode,
+            template,
+        } = options;
+        let guild_id = interaction.cached_guild.guild_id;
+        let level_up_mode = match mode {
+            ConfigLevelUpModeOption::Channel => LevelUpMode::Channel,
+            ConfigLevelUpModeOption::Current => LevelUpMode::Current,
+            ConfigLevelUpModeOption::Direct => LevelUpMode::Direct,
+            ConfigLevelUpModeOption::Off => LevelUpMode::Off,
+        };
+        let level_up_channel_id =
+            channel_id.or(*interaction.cached_guild.level_up_channel_id.read());
+        let level_up_template =
+            template.unwrap_or(interaction.cached_guild.level_up_template.read().clone());
+        let description = match (level_up_mode, level_up_channel_id) {
+            (LevelUpMode::Channel, None) => {
+                "Please provide a channel to announce level ups in.".to_owned()
+            }
+            _ => {
+                context
+                    .database
+                    .update_level_up(
+                        guild_id,
+                        level_up_mode,
+                        level_up_channel_id,
+                        level_up_template.clone(),
+                    )
+                    .await?;
+                context.cache.update_guild(
+                    guild_id,
+                    GuildUpdate {
+                        level_up_channel_id: Some(level_up_channel_id),
+                        level_up_mode: Some(level_up_mode),
+                        level_up_template: Some(level_up_template.clone()),
+                        ..Default::default()
+                    },
+                );
+
+                let destination = match level_up_mode {
+                    LevelUpMode::Channel => format!("in <#{}>", level_up_channel_id.unwrap()),
+                    LevelUpMode::Current => "in the channel the member earned XP in".to_owned(),
+                    LevelUpMode::Direct => "in a direct message".to_owned(),
+                    LevelUpMode::Off => {
+                        return interaction
+                            .context
+                            .update_response(UpdatePayload {
+                                embeds: vec![EmbedBuilder::new()
+                                    .color(0xF8F8FF)
+                                    .description("Level ups will no longer be announced.")
+                                    .build()],
+                                ..Default::default()
+                            })
+                            .await;
+                    }
+                };
+
+                format!(
+                    "Level ups will now be announced {destination} with the following message:\n>>> {level_up_template}"
+                )
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    database::MultiplierMode,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandOption, CreateOption)]
+pub enum ConfigMultiplierModeOption {
+    #[option(name = "Highest multiplier only", value = "max")]
+    Max,
+    #[option(name = "Multiply all multipliers", value = "product")]
+    Product,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set how guild, channel and role multipliers are combined",
+    name = "set-multiplier-mode"
+)]
+pub struct ConfigSetMultiplierModeCommand {
+    #[command(desc = "The combination rule")]
+    mode: ConfigMultiplierModeOption,
+}
+
+impl ConfigSetMultiplierModeCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            mode,
+        } = options;
+        let (multiplier_mode, description) = match mode {
+            ConfigMultiplierModeOption::Max => (
+                MultiplierMode::Max,
+                "Members will now earn XP using only their highest applicable multiplier.",
+            ),
+            ConfigMultiplierModeOption::Product => (
+                MultiplierMode::Product,
+                "Members will now earn XP using the product of all applicable multipliers.",
+            ),
+        };
+
+        context
+            .database
+            .update_multiplier_mode(interaction.cached_guild.guild_id, multiplier_mode)
+            .await?;
+        context.cache.update_guild(
+            interaction.cached_guild.guild_id,
+            GuildUpdate {
+                multiplier_mode: Some(multiplier_mode),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
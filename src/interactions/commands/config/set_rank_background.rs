@@ -0,0 +1,68 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    interactions::commands::rank_card::download_background,
+    types::{
+        context::Context,
+        database::RankCardThemeUpdate,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Set the server's rank card background", name = "set-rank-background")]
+pub struct ConfigSetRankBackgroundCommand {
+    #[command(desc = "A PNG, JPEG, GIF or WebP image, cropped to 875x250")]
+    background: Attachment,
+}
+
+impl ConfigSetRankBackgroundCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let description = match download_background(context, &options.background).await? {
+            Ok(background) => {
+                context
+                    .database
+                    .update_rank_card_theme(
+                        interaction.cached_guild.guild_id,
+                        None,
+                        RankCardThemeUpdate {
+                            background: Some(background),
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+
+                "The rank card background has been updated.".to_owned()
+            }
+            Err(description) => description,
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the experience multiplier for a role (1 removes it)",
+    name = "set-role-multiplier"
+)]
+pub struct ConfigSetRoleMultiplierCommand {
+    #[command(desc = "The multiplier", max_value = 5f64, min_value = 0.1f64)]
+    multiplier: f64,
+    #[command(desc = "The role", rename = "role")]
+    role_id: Id<RoleMarker>,
+}
+
+impl ConfigSetRoleMultiplierCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            multiplier,
+            role_id,
+        } = options;
+        let guild_id = interaction.cached_guild.guild_id;
+        let role_multiplier = f64::trunc(multiplier * 10.0) / 10.0;
+        let mut role_multipliers = interaction.cached_guild.role_multipliers.read().clone();
+        let description = if role_multiplier.eq(&1.0) {
+            context
+                .database
+                .remove_role_multiplier(guild_id, role_id)
+                .await?;
+
+            role_multipliers.remove(&role_id);
+
+            format!("<@&{role_id}> no longer has an XP multiplier.")
+        } else {
+            context
+                .database
+                .insert_role_multiplier(guild_id, role_id, role_multiplier)
+                .await?;
+
+            role_multipliers.insert(role_id, role_multiplier);
+
+            format!("The XP multiplier for <@&{role_id}> is now {role_multiplier}x.")
+        };
+
+        context.cache.update_guild(
+            guild_id,
+            GuildUpdate {
+                role_multipliers: Some(role_multipliers),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    database::RoleStacking,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandOption, CreateOption)]
+pub enum ConfigRoleStackingOption {
+    #[option(name = "Keep only the highest level role", value = "highest_only")]
+    HighestOnly,
+    #[option(name = "Keep every earned level role", value = "stack")]
+    Stack,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set whether members keep every earned level role",
+    name = "set-role-stacking"
+)]
+pub struct ConfigSetRoleStackingCommand {
+    #[command(desc = "The role stacking mode")]
+    mode: ConfigRoleStackingOption,
+}
+
+impl ConfigSetRoleStackingCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            mode,
+        } = options;
+        let (role_stacking, description) = match mode {
+            ConfigRoleStackingOption::HighestOnly => (
+                RoleStacking::HighestOnly,
+                "Members will now keep only their highest level role.",
+            ),
+            ConfigRoleStackingOption::Stack => (
+                RoleStacking::Stack,
+                "Members will now keep every level role they have earned.",
+            ),
+        };
+
+        context
+            .database
+            .update_role_stacking(interaction.cached_guild.guild_id, role_stacking)
+            .await?;
+        context.cache.update_guild(
+            interaction.cached_guild.guild_id,
+            GuildUpdate {
+                role_stacking: Some(role_stacking),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::GuildUpdate,
+        context::Context,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::voice::settle_voice_channel,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the voice experience rates for speakers, streamers and stage audiences",
+    name = "set-voice-bonus"
+)]
+pub struct ConfigSetVoiceBonusCommand {
+    #[command(
+        desc = "The rate for suppressed stage audience members",
+        max_value = 1f64,
+        min_value = 0f64
+    )]
+    audience: Option<f64>,
+    #[command(
+        desc = "The rate for stage speakers and members streaming or with video on",
+        max_value = 5f64,
+        min_value = 1f64
+    )]
+    bonus: Option<f64>,
+}
+
+impl ConfigSetVoiceBonusCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            audience,
+            bonus,
+        } = options;
+        let guild = &interaction.cached_guild;
+        let voice_audience_multiplier = audience.unwrap_or(*guild.voice_audience_multiplier.read());
+        let voice_bonus_multiplier = bonus.unwrap_or(*guild.voice_bonus_multiplier.read());
+        let now = OffsetDateTime::now_utc();
+        let channel_ids = guild.channel_ids.read().clone();
+
+        for channel_id in channel_ids {
+            settle_voice_channel(context, guild, channel_id, now).await;
+        }
+
+        context
+            .database
+            .update_voice_bonus(
+                guild.guild_id,
+                voice_bonus_multiplier,
+                voice_audience_multiplier,
+            )
+            .await?;
+        context.cache.update_guild(
+            guild.guild_id,
+            GuildUpdate {
+                voice_audience_multiplier: Some(voice_audience_multiplier),
+                voice_bonus_multiplier: Some(voice_bonus_multiplier),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Stage speakers and members streaming or with video on will now earn \
+                 {voice_bonus_multiplier}x voice XP, and stage audience members will earn \
+                 {voice_audience_multiplier}x."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::GuildUpdate,
+        context::Context,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::voice::{refresh_voice_channel, settle_voice_channel},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the rules members must meet to earn voice experience",
+    name = "set-voice-rules"
+)]
+pub struct ConfigSetVoiceRulesCommand {
+    #[command(desc = "Whether muted members can earn voice XP")]
+    allow_muted: Option<bool>,
+    #[command(
+        desc = "The minimum number of undeafened members in the channel",
+        max_value = 25,
+        min_value = 1
+    )]
+    min_members: Option<i64>,
+}
+
+impl ConfigSetVoiceRulesCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            allow_muted,
+            min_members,
+        } = options;
+        let guild_id = interaction.cached_guild.guild_id;
+        let voice_allow_muted =
+            allow_muted.unwrap_or(*interaction.cached_guild.voice_allow_muted.read());
+        let voice_min_members =
+            min_members.unwrap_or(*interaction.cached_guild.voice_min_members.read());
+        let now = OffsetDateTime::now_utc();
+        let channel_ids = interaction.cached_guild.channel_ids.read().clone();
+
+        for channel_id in channel_ids.iter() {
+            settle_voice_channel(context, &interaction.cached_guild, *channel_id, now).await;
+        }
+
+        context
+            .database
+            .update_voice_rules(guild_id, voice_min_members, voice_allow_muted)
+            .await?;
+        context.cache.update_guild(
+            guild_id,
+            GuildUpdate {
+                voice_allow_muted: Some(voice_allow_muted),
+                voice_min_members: Some(voice_min_members),
+                ..Default::default()
+            },
+        );
+
+        if let Some(guild) = context.cache.get_guild(guild_id) {
+            for channel_id in channel_ids {
+                refresh_voice_channel(context, &guild, channel_id, now);
+            }
+        }
+
+        let muted = if voice_allow_muted {
+            "Muted members can earn voice XP"
+        } else {
+            "Muted members cannot earn voice XP"
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Members now need at least {voice_min_members} undeafened member(s) in their \
+                 channel to earn voice XP. {muted}, and no XP is earned in the AFK channel."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
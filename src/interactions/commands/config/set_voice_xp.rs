@@ -0,0 +1,112 @@
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::GuildUpdate,
+        context::Context,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::voice::settle_voice_channel,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the rate and limits of experience awarded in voice channels",
+    name = "set-voice-xp"
+)]
+pub struct ConfigSetVoiceXpCommand {
+    #[command(
+        desc = "The maximum voice XP per member per day, 0 for no limit",
+        max_value = 1_000_000,
+        min_value = 0
+    )]
+    daily_cap: Option<i64>,
+    #[command(
+        desc = "The maximum XP per voice session, 0 for no limit",
+        max_value = 1_000_000,
+        min_value = 0
+    )]
+    max_per_session: Option<i64>,
+    #[command(desc = "The XP awarded per minute in voice", max_value = 1000, min_value = 0)]
+    per_minute: Option<i64>,
+}
+
+impl ConfigSetVoiceXpCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            daily_cap,
+            max_per_session,
+            per_minute,
+        } = options;
+        let guild = &interaction.cached_guild;
+        let voice_daily_xp_cap = daily_cap.unwrap_or(*guild.voice_daily_xp_cap.read());
+        let voice_max_session_xp = max_per_session.unwrap_or(*guild.voice_max_session_xp.read());
+        let voice_xp_per_minute = per_minute.unwrap_or(*guild.voice_xp_per_minute.read());
+        let now = OffsetDateTime::now_utc();
+        let channel_ids = guild.channel_ids.read().clone();
+
+        for channel_id in channel_ids {
+            settle_voice_channel(context, guild, channel_id, now).await;
+        }
+
+        context
+            .database
+            .update_voice_xp(
+                guild.guild_id,
+                voice_xp_per_minute,
+                voice_max_session_xp,
+                voice_daily_xp_cap,
+            )
+            .await?;
+        context.cache.update_guild(
+            guild.guild_id,
+            GuildUpdate {
+                voice_daily_xp_cap: Some(voice_daily_xp_cap),
+                voice_max_session_xp: Some(voice_max_session_xp),
+                voice_xp_per_minute: Some(voice_xp_per_minute),
+                ..Default::default()
+            },
+        );
+
+        let format_limit = |limit: i64| {
+            if limit.gt(&0) {
+                format!("{limit} XP")
+            } else {
+                "no limit".to_owned()
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Members will now earn {voice_xp_per_minute} XP per minute in voice, with {} per \
+                 session and {} per day.",
+                format_limit(voice_max_session_xp),
+                format_limit(voice_daily_xp_cap)
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    cache::GuildUpdate,
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the range of experience awarded per message",
+    name = "set-xp-range"
+)]
+pub struct ConfigSetXpRangeCommand {
+    #[command(desc = "The maximum XP per message", max_value = 1000, min_value = 1)]
+    max: i64,
+    #[command(desc = "The minimum XP per message", max_value = 1000, min_value = 1)]
+    min: i64,
+}
+
+impl ConfigSetXpRangeCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            max,
+            min,
+        } = options;
+        let description = if min.gt(&max) {
+            format!("The minimum XP ({min}) cannot be greater than the maximum XP ({max}).")
+        } else {
+            context
+                .database
+                .update_xp_range(interaction.cached_guild.guild_id, min, max)
+                .await?;
+            context.cache.update_guild(
+                interaction.cached_guild.guild_id,
+                GuildUpdate {
+                    max_xp: Some(max),
+                    min_xp: Some(min),
+                    ..Default::default()
+                },
+            );
+
+            format!("Members will now receive between {min} and {max} XP per message.")
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use std::time::Instant;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::MemberUpdate,
+        context::Context,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload},
+        Result,
+    },
+    utility::leveling::{level_for_xp, member_role_ids},
+};
+
+const BATCH_SIZE: usize = 50;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Give every member the level roles they have earned", name = "sync-roles")]
+pub struct ConfigSyncRolesCommand {}
+
+impl ConfigSyncRolesCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let started_at = Instant::now();
+        let guild = &interaction.cached_guild;
+        let level_curve = guild.level_curve.read().clone();
+        let member_ids = guild.member_ids.read().iter().copied().collect::<Vec<_>>();
+        let total = member_ids.len();
+        let mut checked = 0;
+        let mut failed = 0;
+        let mut updated = 0;
+        let mut progress_message_id = None;
+
+        for batch in member_ids.chunks(BATCH_SIZE) {
+            for user_id in batch {
+                checked += 1;
+
+                let Some(member) = context.cache.get_member(guild.guild_id, *user_id) else {
+                    continue;
+                };
+
+                if member.bot {
+                    continue;
+                }
+
+                let current_role_ids = member.role_ids.read().clone();
+                let role_ids = member_role_ids(
+                    &current_role_ids,
+                    &guild.levels.read(),
+                    level_for_xp(&level_curve, *member.xp.read()),
+                    *guild.role_stacking.read(),
+                );
+
+                if role_ids.eq(&current_role_ids) {
+                    continue;
+                }
+
+                if context
+                    .http
+                    .update_guild_member(guild.guild_id, *user_id)
+                    .roles(&role_ids.iter().copied().collect::<Vec<Id<RoleMarker>>>())
+                    .await
+                    .is_err()
+                {
+                    failed += 1;
+
+                    continue;
+                }
+
+                context.cache.update_member(
+                    guild.guild_id,
+                    *user_id,
+                    MemberUpdate {
+                        role_ids: Some(role_ids),
+                        ..Default::default()
+                    },
+                );
+
+                updated += 1;
+            }
+
+            if checked.lt(&total) {
+                let embed = EmbedBuilder::new()
+                    .color(0xF8F8FF)
+                    .description(format!(
+                        "Syncing level roles... {checked}/{total} members checked, {updated} \
+                         updated."
+                    ))
+                    .build();
+
+                interaction
+                    .update_progress(context, &mut progress_message_id, started_at, embed)
+                    .await
+                    .ok();
+            }
+        }
+
+        let mut description =
+            format!("Synced level roles for {total} member(s), {updated} of which were updated.");
+
+        if failed.gt(&0) {
+            description.push_str(&format!(
+                "\n{failed} member(s) could not be updated, make sure my highest role is above \
+                 every level role."
+            ));
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .update_progress(context, &mut progress_message_id, started_at, embed)
+            .await?;
+
+        Ok(())
+    }
+}
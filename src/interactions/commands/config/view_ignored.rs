@@ -0,0 +1,101 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::types::{
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "View ignored roles and channels", name = "view-ignored")]
+pub struct ConfigViewIgnoredCommand {}
+
+impl ConfigViewIgnoredCommand {
+    pub async fn run(
+        _context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let ignored_role_ids = interaction.cached_guild.ignored_role_ids.read().clone();
+        let ignored_text_channel_ids = interaction
+            .cached_guild
+            .ignored_text_channel_ids
+            .read()
+            .clone();
+        let ignored_voice_channel_ids = interaction
+            .cached_guild
+            .ignored_voice_channel_ids
+            .read()
+            .clone();
+        let mut embed_builder = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .title(format!("{} ignored roles and channels", interaction.cached_guild.name));
+
+        if ignored_role_ids.is_empty()
+            && ignored_text_channel_ids.is_empty()
+            && ignored_voice_channel_ids.is_empty()
+        {
+            embed_builder =
+                embed_builder.description("There are no ignored roles or channels in this guild.");
+        }
+
+        if !ignored_role_ids.is_empty() {
+            embed_builder = embed_builder.field(
+                EmbedFieldBuilder::new(
+                    "Roles",
+                    ignored_role_ids
+                        .iter()
+                        .map(|role_id| format!("- <@&{role_id}>"))
+                        .collect::<Vec<String>>()
+                        .join("\n"),
+                )
+                .build(),
+            );
+        }
+
+        if !ignored_text_channel_ids.is_empty() {
+            embed_builder = embed_builder.field(
+                EmbedFieldBuilder::new(
+                    "Text channels",
+                    ignored_text_channel_ids
+                        .iter()
+                        .map(|channel_id| format!("- <#{channel_id}>"))
+                        .collect::<Vec<String>>()
+                        .join("\n"),
+                )
+                .build(),
+            );
+        }
+
+        if !ignored_voice_channel_ids.is_empty() {
+            embed_builder = embed_builder.field(
+                EmbedFieldBuilder::new(
+                    "Voice channels",
+                    ignored_voice_channel_ids
+                        .iter()
+                        .map(|channel_id| format!("- <#{channel_id}>"))
+                        .collect::<Vec<String>>()
+                        .join("\n"),
+                )
+                .build(),
+            );
+        }
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed_builder.build()],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::types::{
+    context::Context,
+    database::MultiplierMode,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "View experience multipliers", name = "view-multipliers")]
+pub struct ConfigViewMultipliersCommand {}
+
+impl ConfigViewMultipliersCommand {
+    pub async fn run(
+        _context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let xp_multiplier = *interaction.cached_guild.xp_multiplier.read();
+        let multiplier_mode = match *interaction.cached_guild.multiplier_mode.read() {
+            MultiplierMode::Max => "highest multiplier only",
+            MultiplierMode::Product => "multiply all multipliers",
+        };
+        let channel_multipliers = interaction.cached_guild.channel_multipliers.read().clone();
+        let role_multipliers = interaction.cached_guild.role_multipliers.read().clone();
+        let mut embed_builder = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "**Guild:** {xp_multiplier}x\n**Mode:** {multiplier_mode}"
+            ))
+            .title(format!("{} XP multipliers", interaction.cached_guild.name));
+
+        if !channel_multipliers.is_empty() {
+            embed_builder = embed_builder.field(
+                EmbedFieldBuilder::new(
+                    "Channels",
+                    channel_multipliers
+                        .iter()
+                        .map(|(channel_id, multiplier)| format!("- <#{channel_id}>: {multiplier}x"))
+                        .collect::<Vec<String>>()
+                        .join("\n"),
+                )
+                .build(),
+            );
+        }
+
+        if !role_multipliers.is_empty() {
+            embed_builder = embed_builder.field(
+                EmbedFieldBuilder::new(
+                    "Roles",
+                    role_multipliers
+                        .iter()
+                        .map(|(role_id, multiplier)| format!("- <@&{role_id}>: {multiplier}x"))
+                        .collect::<Vec<String>>()
+                        .join("\n"),
+                )
+                .build(),
+            );
+        }
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed_builder.build()],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,30 +1,152 @@
-use std::{sync::Arc, time::Duration};
+use std::sync::Arc;
 
 use thousands::Separable;
-use tokio::time::sleep;
-use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::channel::message::{
-    component::{ActionRow, Button, ButtonStyle},
-    Component,
-    ReactionType,
+use time::{ext::NumericalDuration, Date, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+        Embed,
+        ReactionType,
+    },
+    id::{marker::UserMarker, Id},
 };
 use twilight_util::builder::embed::{EmbedBuilder, EmbedFooterBuilder};
 
-use crate::types::{
-    cache::Member,
-    context::Context,
-    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
-    Result,
+use crate::{
+    interactions::commands::rank::get_ranked_members,
+    types::{
+        cache::{Guild, Member},
+        context::Context,
+        database::LevelCurve,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::{
+        decimal::abbreviate,
+        image::{default_avatar_url, get_leaderboard_image, LeaderboardImageRow},
+        leveling::level_for_xp,
+    },
 };
 
+#[derive(Clone, Copy, CommandOption, CreateOption)]
+pub enum LeaderboardPeriodOption {
+    #[option(name = "All time", value = "all")]
+    All,
+    #[option(name = "Today", value = "day")]
+    Day,
+    #[option(name = "Last 30 days", value = "month")]
+    Month,
+    #[option(name = "Last 7 days", value = "week")]
+    Week,
+}
+
+impl LeaderboardPeriodOption {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderboardPeriodOption::All => "all",
+            LeaderboardPeriodOption::Day => "day",
+            LeaderboardPeriodOption::Month => "month",
+            LeaderboardPeriodOption::Week => "week",
+        }
+    }
+
+    pub fn since(&self) -> Option<Date> {
+        let today = OffsetDateTime::now_utc().date();
+
+        match self {
+            LeaderboardPeriodOption::All => None,
+            LeaderboardPeriodOption::Day => Some(today),
+            LeaderboardPeriodOption::Month => today.checked_sub(29.days()),
+            LeaderboardPeriodOption::Week => today.checked_sub(6.days()),
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            LeaderboardPeriodOption::All => "leaderboard",
+            LeaderboardPeriodOption::Day => "leaderboard for today",
+            LeaderboardPeriodOption::Month => "leaderboard for the last 30 days",
+            LeaderboardPeriodOption::Week => "leaderboard for the last 7 days",
+        }
+    }
+}
+
+impl From<&str> for LeaderboardPeriodOption {
+    fn from(value: &str) -> Self {
+        match value {
+            "day" => LeaderboardPeriodOption::Day,
+            "month" => LeaderboardPeriodOption::Month,
+            "week" => LeaderboardPeriodOption::Week,
+            _ => LeaderboardPeriodOption::All,
+        }
+    }
+}
+
+#[derive(Clone, Copy, CommandOption, CreateOption)]
+pub enum LeaderboardStyleOption {
+    #[option(name = "Embed", value = "embed")]
+    Embed,
+    #[option(name = "Image", value = "image")]
+    Image,
+}
+
+#[derive(Clone, Copy)]
+pub struct LeaderboardView {
+    pub period: LeaderboardPeriodOption,
+    pub season: Option<i64>,
+}
+
+impl LeaderboardView {
+    pub fn custom_id(&self, action: &str, page: usize) -> String {
+        format!(
+            "leaderboard-{action}:{page}:{}:{}",
+            self.period.as_str(),
+            self.season.map_or_else(String::new, |season| season.to_string())
+        )
+    }
+
+    pub fn from_custom_id(custom_id: &str) -> (&str, usize, Self) {
+        let mut split = custom_id.split(':');
+        let name = split.next().unwrap_or_default();
+        let page = split
+            .next()
+            .and_then(|page| page.parse::<usize>().ok())
+            .unwrap_or_default();
+        let period = LeaderboardPeriodOption::from(split.next().unwrap_or_default());
+        let season = split.next().and_then(|season| season.parse::<i64>().ok());
+
+        (
+            name,
+            page,
+            LeaderboardView {
+                period,
+                season,
+            },
+        )
+    }
+
+    fn show_level(&self) -> bool {
+        self.season.is_none() && matches!(self.period, LeaderboardPeriodOption::All)
+    }
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(desc = "View the server's leaderboard", name = "leaderboard")]
-pub struct LeaderboardCommand {}
+pub struct LeaderboardCommand {
+    #[command(desc = "The period to rank experience over")]
+    period: Option<LeaderboardPeriodOption>,
+    #[command(desc = "The season to view, which takes priority over the period", min_value = 1)]
+    season: Option<i64>,
+    #[command(desc = "Whether to show the leaderboard as an embed or an image")]
+    style: Option<LeaderboardStyleOption>,
+}
 
 impl LeaderboardCommand {
     pub async fn run(
         context: &Context,
-        interaction: &ApplicationCommandInteraction<'_>,
+        interaction: &mut ApplicationCommandInteraction<'_>,
     ) -> Result<()> {
         interaction
             .context
@@ -33,52 +155,27 @@ impl LeaderboardCommand {
             })
             .await?;
 
-        let mut leaderboard = interaction
-            .cached_guild
-            .member_ids
-            .read()
-            .iter()
-            .filter_map(|user_id| {
-                let member = context
-                    .cache
-                    .get_member(interaction.cached_guild.guild_id, *user_id);
-
-                match member {
-                    Some(member) if member.xp.read().gt(&0) => Some(member),
-                    _ => None,
-                }
-            })
-            .collect::<Vec<Arc<Member>>>();
-
-        leaderboard.sort_unstable_by(|a, b| {
-            if !b.xp.read().eq(&*a.xp.read()) {
-                b.xp.read().cmp(&a.xp.read())
-            } else if !b
-                .last_message_timestamp
-                .read()
-                .eq(&a.last_message_timestamp.read())
-            {
-                b.last_message_timestamp
-                    .read()
-                    .cmp(&a.last_message_timestamp.read())
-            } else {
-                b.joined_voice_timestamp
-                    .read()
-                    .cmp(&a.joined_voice_timestamp.read())
-            }
-        });
-
-        let mut embed_builder = EmbedBuilder::new()
-            .color(0xF8F8FF)
-            .title(format!("{} leaderboard", interaction.cached_guild.name));
+        let options = LeaderboardCommand::from_interaction(interaction.input_data())?;
+        let view = LeaderboardView {
+            period: options.period.unwrap_or(LeaderboardPeriodOption::All),
+            season: options.season,
+        };
+        let leaderboard = get_leaderboard(context, &interaction.cached_guild, view).await?;
 
         if leaderboard.is_empty() {
-            embed_builder = embed_builder.description("There are no members with XP in this guild");
+            let embed = EmbedBuilder::new()
+                .color(0xF8F8FF)
+                .description(match view.season {
+                    Some(season) => format!("There are no members with XP in season {season}"),
+                    None => "There are no members with XP in this guild".to_owned(),
+                })
+                .title(format_leaderboard_title(&interaction.cached_guild.name, view))
+                .build();
 
             interaction
                 .context
                 .update_response(UpdatePayload {
-                    embeds: vec![embed_builder.build()],
+                    embeds: vec![embed],
                     ..Default::default()
                 })
                 .await?;
@@ -86,88 +183,231 @@ impl LeaderboardCommand {
             return Ok(());
         }
 
-        embed_builder = embed_builder.description(
-            leaderboard
-                .iter()
-                .take(10)
-                .enumerate()
-                .map(|(index, member)| {
-                    let rank = index + 1;
-                    let username = if member.discriminator == 0 {
-                        member.username.clone()
-                    } else {
-                        format!("{}#{:04}", member.username, member.discriminator)
-                    };
-
-                    format!(
-                        "#{} - **{}** ({} XP)",
-                        rank,
-                        username,
-                        member.xp.read().separate_with_commas()
+        let level_curve = interaction.cached_guild.level_curve.read().clone();
+
+        if let Some(LeaderboardStyleOption::Image) = options.style {
+            let mut rows = Vec::new();
+
+            for (index, (member, xp)) in leaderboard.iter().take(10).enumerate() {
+                let avatar_url = format!("{}?size=128", member.avatar_url.read());
+                let avatar_image = context
+                    .renderer
+                    .avatar(
+                        &context.hyper,
+                        &avatar_url,
+                        &default_avatar_url(member.user_id, member.discriminator),
                     )
+                    .await;
+
+                rows.push(LeaderboardImageRow {
+                    avatar_image,
+                    rank: index + 1,
+                    total_xp: *member.xp.read(),
+                    username: member.username.clone(),
+                    xp_text: if view.show_level() {
+                        format!("{} XP", abbreviate(*xp))
+                    } else {
+                        format!("+{} XP", abbreviate(*xp))
+                    },
+                });
+            }
+
+            let theme = context
+                .database
+                .get_rank_card_theme(interaction.cached_guild.guild_id, None)
+                .await?;
+            let background_image = context
+                .renderer
+                .background(interaction.cached_guild.guild_id, &theme)
+                .await?;
+            let title = format_leaderboard_title(&interaction.cached_guild.name, view);
+            let attachment = context
+                .renderer
+                .render(move || {
+                    get_leaderboard_image(background_image, level_curve, &theme, title, rows)
                 })
-                .collect::<Vec<String>>()
-                .join("\n"),
-        );
+                .await?;
 
-        if leaderboard.len() > 10 {
-            embed_builder = embed_builder.footer(EmbedFooterBuilder::new(format!(
-                "Page 1 of {}",
-                (leaderboard.len() as f32 / 10.0).ceil()
-            )))
+            interaction
+                .context
+                .update_response(UpdatePayload {
+                    attachments: vec![attachment],
+                    ..Default::default()
+                })
+                .await?;
+
+            return Ok(());
         }
 
-        let components = if leaderboard.len() > 10 {
-            vec![Component::ActionRow(ActionRow {
-                components: vec![
-                    Component::Button(Button {
-                        custom_id: Some("leaderboard-previous".to_owned()),
-                        disabled: false,
-                        emoji: Some(ReactionType::Unicode {
-                            name: "⬅️".to_owned(),
-                        }),
-                        label: None,
-                        style: ButtonStyle::Primary,
-                        url: None,
-                    }),
-                    Component::Button(Button {
-                        custom_id: Some("leaderboard-next".to_owned()),
-                        disabled: false,
-                        emoji: Some(ReactionType::Unicode {
-                            name: "➡️".to_owned(),
-                        }),
-                        label: None,
-                        style: ButtonStyle::Primary,
-                        url: None,
-                    }),
-                ],
-            })]
-        } else {
-            Vec::new()
-        };
-        let embed = embed_builder.build();
+        let (embed, components) = format_leaderboard_message(
+            &interaction.cached_guild.name,
+            &level_curve,
+            &leaderboard,
+            0,
+            view,
+            interaction.user_id,
+        );
 
         interaction
             .context
             .update_response(UpdatePayload {
-                components: components.clone(),
-                embeds: vec![embed.clone()],
+                components,
+                embeds: vec![embed],
                 ..Default::default()
             })
             .await?;
 
-        if leaderboard.len() > 10 {
-            sleep(Duration::from_secs(15)).await;
+        Ok(())
+    }
+}
 
-            interaction
-                .context
-                .update_response(UpdatePayload {
-                    embeds: interaction.context.response().await?.embeds,
-                    ..Default::default()
+pub fn format_leaderboard_message(
+    guild_name: &str,
+    level_curve: &LevelCurve,
+    leaderboard: &[(Arc<Member>, i64)],
+    page: usize,
+    view: LeaderboardView,
+    user_id: Id<UserMarker>,
+) -> (Embed, Vec<Component>) {
+    let total_pages = ((leaderboard.len() as f32 / 10.0).ceil() as usize).max(1);
+    let page = page.min(total_pages - 1);
+    let mut embed_builder = EmbedBuilder::new()
+        .color(0xF8F8FF)
+        .description(format_leaderboard_page(level_curve, leaderboard, page, view, user_id))
+        .title(format_leaderboard_title(guild_name, view));
+
+    if total_pages == 1 {
+        return (embed_builder.build(), Vec::new());
+    }
+
+    embed_builder = embed_builder.footer(EmbedFooterBuilder::new(format!(
+        "Page {} of {total_pages}",
+        page + 1
+    )));
+
+    let buttons = [
+        ("first", "⏮️"),
+        ("previous", "⬅️"),
+        ("next", "➡️"),
+        ("last", "⏭️"),
+        ("me", "📍"),
+    ];
+    let components = vec![Component::ActionRow(ActionRow {
+        components: buttons
+            .into_iter()
+            .map(|(action, emoji)| {
+                Component::Button(Button {
+                    custom_id: Some(view.custom_id(action, page)),
+                    disabled: false,
+                    emoji: Some(ReactionType::Unicode {
+                        name: emoji.to_owned(),
+                    }),
+                    label: None,
+                    style: ButtonStyle::Primary,
+                    url: None,
                 })
-                .await?;
-        }
+            })
+            .collect(),
+    })];
 
-        Ok(())
+    (embed_builder.build(), components)
+}
+
+fn format_leaderboard_page(
+    level_curve: &LevelCurve,
+    leaderboard: &[(Arc<Member>, i64)],
+    page: usize,
+    view: LeaderboardView,
+    user_id: Id<UserMarker>,
+) -> String {
+    leaderboard
+        .iter()
+        .enumerate()
+        .skip(page * 10)
+        .take(10)
+        .map(|(index, (member, xp))| {
+            let rank = index + 1;
+            let username = if member.discriminator == 0 {
+                member.username.clone()
+            } else {
+                format!("{}#{:04}", member.username, member.discriminator)
+            };
+            let row = if view.show_level() {
+                format!(
+                    "#{} - **{}** (Lv. {}, {} XP)",
+                    rank,
+                    username,
+                    level_for_xp(level_curve, *xp),
+                    xp.separate_with_commas()
+                )
+            } else {
+                format!("#{} - **{}** (+{} XP)", rank, username, xp.separate_with_commas())
+            };
+
+            if member.user_id.eq(&user_id) {
+                format!("__{row}__ ◀")
+            } else {
+                row
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn format_leaderboard_title(guild_name: &str, view: LeaderboardView) -> String {
+    match view.season {
+        Some(season) => format!("{guild_name} season {season} leaderboard"),
+        None => format!("{guild_name} {}", view.period.title()),
+    }
+}
+
+pub async fn get_leaderboard(
+    context: &Context,
+    guild: &Guild,
+    view: LeaderboardView,
+) -> Result<Vec<(Arc<Member>, i64)>> {
+    if let Some(season_number) = view.season {
+        let Some(season) = context.database.get_season(guild.guild_id, season_number).await? else {
+            return Ok(Vec::new());
+        };
+        let leaderboard = context
+            .database
+            .get_season_leaderboard(guild.guild_id, &season)
+            .await?
+            .into_iter()
+            .filter_map(|(user_id, xp)| {
+                context
+                    .cache
+                    .get_member(guild.guild_id, user_id)
+                    .map(|member| (member, xp))
+            })
+            .collect();
+
+        return Ok(leaderboard);
     }
+
+    let Some(since) = view.period.since() else {
+        return Ok(get_ranked_members(context, guild)
+            .into_iter()
+            .map(|member| {
+                let xp = *member.xp.read();
+
+                (member, xp)
+            })
+            .collect());
+    };
+    let leaderboard = context
+        .database
+        .get_xp_history_leaderboard(guild.guild_id, since)
+        .await?
+        .into_iter()
+        .filter_map(|(user_id, xp)| {
+            context
+                .cache
+                .get_member(guild.guild_id, user_id)
+                .map(|member| (member, xp))
+        })
+        .collect();
+
+    Ok(leaderboard)
 }
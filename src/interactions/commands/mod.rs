@@ -1,16 +1,24 @@
+pub mod compare;
 pub mod config;
 pub mod latency;
 pub mod leaderboard;
 pub mod rank;
+pub mod rank_card;
+pub mod season;
+pub mod xp;
 
 use twilight_interactions::command::CreateCommand;
 use twilight_model::application::command::Command;
 
 pub fn get_commands() -> Vec<Command> {
     vec![
+        compare::CompareCommand::create_command().into(),
         config::ConfigCommand::create_command().into(),
         latency::LatencyCommand::create_command().into(),
         leaderboard::LeaderboardCommand::create_command().into(),
         rank::RankCommand::create_command().into(),
+        rank_card::RankCardCommand::create_command().into(),
+        season::SeasonCommand::create_command().into(),
+        xp::XpCommand::create_command().into(),
     ]
 }
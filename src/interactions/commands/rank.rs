@@ -1,17 +1,28 @@
 use std::{collections::HashSet, sync::Arc};
 
-use skia_safe::{Data, Image};
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::{marker::UserMarker, Id};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
 
 use crate::{
     types::{
-        cache::Member,
+        cache::{Guild, Member},
         context::Context,
         interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
         Result,
     },
-    utility::image::get_profile,
+    utility::{
+        image::{
+            default_avatar_url,
+            get_animated_profile,
+            get_profile,
+            member_avatar_url,
+            Profile,
+        },
+        leveling::level_for_xp,
+    },
 };
 
 #[derive(CommandModel, CreateCommand)]
@@ -37,102 +48,71 @@ impl RankCommand {
         let user_id = RankCommand::from_interaction(interaction.input_data())?
             .user_id
             .unwrap_or(interaction.user_id);
-        let (avatar_url, username, xp) = if let Some(member) =
-            context.cache.get_member(guild_id, user_id)
-        {
-            (
-                member.avatar_url.read().to_owned(),
-                member.username.to_owned(),
-                member.xp.read().to_owned(),
+        let (avatar_url, discriminator, username, xp) =
+            get_member_details(context, guild_id, user_id).await?;
+        let leaderboard = get_ranked_members(context, &interaction.cached_guild);
+        let avatar_image = context
+            .renderer
+            .avatar(
+                &context.hyper,
+                &format!("{avatar_url}?size=512"),
+                &default_avatar_url(user_id, discriminator),
             )
-        } else {
-            let member = context
-                .http
-                .guild_member(guild_id, user_id)
-                .await?
-                .model()
-                .await?;
-            let avatar_url = if let Some(member_avatar) = member.avatar {
-                format!("https://cdn.discordapp.com/guilds/{guild_id}/users/{user_id}/avatars/{member_avatar}.png")
-            } else if let Some(user_avatar) = member.user.avatar {
-                format!("https://cdn.discordapp.com/avatars/{user_id}/{user_avatar}.png")
-            } else {
-                let index = if member.user.discriminator == 0 {
-                    (user_id.get() >> 22) % 6
-                } else {
-                    (member.user.discriminator % 5) as u64
-                };
-
-                format!("https://cdn.discordapp.com/embed/avatars/{index}.png")
-            };
-            let (xp, last_message_timestamp) = context
-                .database
-                .get_member(guild_id, user_id)
-                .await?
-                .unwrap_or_default();
-
-            context.cache.insert_member(
-                avatar_url.clone(),
-                member.user.bot,
-                member.user.discriminator,
-                guild_id,
-                None,
-                last_message_timestamp,
-                HashSet::from_iter(member.roles.to_owned()),
-                user_id,
-                member.user.name.to_owned(),
-                None,
-                xp,
-            );
-
-            (avatar_url, member.user.name, xp)
-        };
-        let mut leaderboard = interaction
+            .await;
+        let rank = get_member_rank(&interaction.cached_guild, &leaderboard, user_id);
+        let ranked_members = leaderboard.len().max(rank);
+        let level_curve = interaction.cached_guild.level_curve.read().clone();
+        let level = level_for_xp(&level_curve, xp);
+        let next_reward_level = interaction
             .cached_guild
-            .member_ids
+            .levels
             .read()
             .iter()
-            .filter_map(|user_id| {
-                let member = context
-                    .cache
-                    .get_member(interaction.cached_guild.guild_id, *user_id);
-
-                match member {
-                    Some(member) if member.xp.read().gt(&0) => Some(member),
-                    _ => None,
-                }
-            })
-            .collect::<Vec<Arc<Member>>>();
-
-        leaderboard.sort_unstable_by(|a, b| {
-            if !b.xp.read().eq(&*a.xp.read()) {
-                b.xp.read().cmp(&a.xp.read())
-            } else if !b
-                .last_message_timestamp
-                .read()
-                .eq(&a.last_message_timestamp.read())
-            {
-                b.last_message_timestamp
-                    .read()
-                    .cmp(&a.last_message_timestamp.read())
-            } else {
-                b.joined_voice_timestamp
-                    .read()
-                    .cmp(&a.joined_voice_timestamp.read())
-            }
-        });
+            .filter(|(role_level, role_ids)| role_level.gt(&level) && !role_ids.is_empty())
+            .min_by_key(|(role_level, _)| *role_level)
+            .cloned();
+        let next_reward = next_reward_level.and_then(|(reward_level, reward_role_ids)| {
+            let role_names = interaction.cached_guild.role_names.read();
 
-        let formatted_uri = format!("{avatar_url}?size=512");
-        let response = context.hyper.get(formatted_uri.parse()?).await?;
-        let avatar_image_bytes = hyper::body::to_bytes(response.into_body()).await?;
-        let avatar_image_data = Data::new_copy(&avatar_image_bytes);
-        let avatar_image = Image::from_encoded(avatar_image_data).unwrap();
-        let rank = leaderboard
-            .into_iter()
-            .position(|member| member.user_id.eq(&user_id))
-            .unwrap_or(interaction.cached_guild.member_ids.read().len() - 1)
-            + 1;
-        let attachment = get_profile(guild_id, avatar_image, username, rank, xp);
+            reward_role_ids
+                .iter()
+                .find_map(|role_id| role_names.get(role_id).cloned())
+                .map(|role_name| (reward_level, role_name))
+        });
+        let (message_xp, voice_xp) = context
+            .database
+            .get_member_xp_sources(guild_id, user_id)
+            .await?;
+        let theme = context
+            .database
+            .get_rank_card_theme(guild_id, Some(user_id))
+            .await?;
+        let background_image = context.renderer.background(guild_id, &theme).await?;
+        let profile = Profile {
+            avatar_image,
+            background_image,
+            level_curve,
+            message_xp,
+            next_reward,
+            rank,
+            ranked_members,
+            theme,
+            username,
+            voice_xp,
+            xp,
+        };
+        let animated_avatar = context
+            .renderer
+            .animated_avatar(&context.hyper, &format!("{avatar_url}?size=256"))
+            .await;
+        let attachment = context
+            .renderer
+            .render(move || {
+                animated_avatar
+                    .and_then(|avatar_frames| get_animated_profile(&profile, avatar_frames))
+                    .unwrap_or_else(|| get_profile(profile))
+            })
+            .await?;
 
         interaction
             .context
@@ -145,3 +125,92 @@ impl RankCommand {
         Ok(())
     }
 }
+
+pub async fn get_member_details(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+) -> Result<(String, u16, String, i64)> {
+    if let Some(member) = context.cache.get_member(guild_id, user_id) {
+        return Ok((
+            member.avatar_url.read().to_owned(),
+            member.discriminator,
+            member.username.to_owned(),
+            member.xp.read().to_owned(),
+        ));
+    }
+
+    let member = context
+        .http
+        .guild_member(guild_id, user_id)
+        .await?
+        .model()
+        .await?;
+    let avatar_url = member_avatar_url(
+        guild_id,
+        user_id,
+        member.avatar,
+        member.user.avatar,
+        member.user.discriminator,
+    );
+    let (xp, last_message_timestamp) = context
+        .database
+        .get_member(guild_id, user_id)
+        .await?
+        .unwrap_or_default();
+
+    context.cache.insert_member(
+        avatar_url.clone(),
+        member.user.bot,
+        member.user.discriminator,
+        guild_id,
+        None,
+        last_message_timestamp,
+        HashSet::from_iter(member.roles.to_owned()),
+        user_id,
+        member.user.name.to_owned(),
+        None,
+        xp,
+    );
+
+    Ok((avatar_url, member.user.discriminator, member.user.name, xp))
+}
+
+pub fn get_member_rank(
+    guild: &Guild,
+    leaderboard: &[Arc<Member>],
+    user_id: Id<UserMarker>,
+) -> usize {
+    leaderboard
+        .iter()
+        .position(|member| member.user_id.eq(&user_id))
+        .unwrap_or(guild.member_ids.read().len() - 1)
+        + 1
+}
+
+pub fn get_ranked_members(context: &Context, guild: &Guild) -> Vec<Arc<Member>> {
+    let mut leaderboard = guild
+        .member_ids
+        .read()
+        .iter()
+        .filter_map(|user_id| {
+            match context.cache.get_member(guild.guild_id, *user_id) {
+                Some(member) if member.xp.read().gt(&0) => Some(member),
+                _ => None,
+            }
+        })
+        .collect::<Vec<Arc<Member>>>();
+
+    leaderboard.sort_by(|a, b| {
+        b.xp.read()
+            .cmp(&a.xp.read())
+            .then_with(|| {
+                b.last_message_timestamp
+                    .read()
+                    .cmp(&a.last_message_timestamp.read())
+            })
+            .then_with(|| a.user_id.cmp(&b.user_id))
+    });
+
+    leaderboard
+}
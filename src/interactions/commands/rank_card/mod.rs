@@ -0,0 +1,216 @@
+mod reset;
+mod set;
+mod set_default;
+
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::{
+    channel::Attachment,
+    id::{marker::UserMarker, Id},
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use self::{
+    reset::RankCardResetCommand,
+    set::RankCardSetCommand,
+    set_default::RankCardSetDefaultCommand,
+};
+use crate::{
+    types::{
+        context::Context,
+        database::{RankCardBarStyle, RankCardFont, RankCardThemeUpdate},
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::{constants::MAX_BACKGROUND_BYTES, image::fit_background},
+};
+
+#[derive(CommandOption, CreateOption)]
+pub enum RankCardBarStyleOption {
+    #[option(name = "Rounded", value = "rounded")]
+    Rounded,
+    #[option(name = "Square", value = "square")]
+    Square,
+    #[option(name = "Thin", value = "thin")]
+    Thin,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum RankCardFontOption {
+    #[option(name = "Monospace", value = "monospace")]
+    Monospace,
+    #[option(name = "Serif", value = "serif")]
+    Serif,
+    #[option(name = "Source Sans 3", value = "source_sans_3")]
+    SourceSans3,
+}
+
+struct RankCardThemeOptions {
+    accent: Option<String>,
+    background: Option<Attachment>,
+    bar_style: Option<RankCardBarStyleOption>,
+    font: Option<RankCardFontOption>,
+    opacity: Option<i64>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Customize rank cards", name = "rank-card")]
+pub enum RankCardCommand {
+    #[command(name = "reset")]
+    Reset(RankCardResetCommand),
+    #[command(name = "set")]
+    Set(RankCardSetCommand),
+    #[command(name = "set-default")]
+    SetDefault(RankCardSetDefaultCommand),
+}
+
+impl RankCardCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        let command = RankCardCommand::from_interaction(interaction.input_data())?;
+        let requires_administrator = match &command {
+            RankCardCommand::Reset(options) => options.default.unwrap_or(false),
+            RankCardCommand::Set(_) => false,
+            RankCardCommand::SetDefault(_) => true,
+        };
+
+        if requires_administrator
+            && !interaction
+                .require_administrator("change the server's default rank card")
+                .await?
+        {
+            return Ok(());
+        }
+
+        match command {
+            RankCardCommand::Reset(options) => {
+                RankCardResetCommand::run(context, interaction, options).await?
+            }
+            RankCardCommand::Set(options) => {
+                RankCardSetCommand::run(context, interaction, options).await?
+            }
+            RankCardCommand::SetDefault(options) => {
+                RankCardSetDefaultCommand::run(context, interaction, options).await?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn update_theme(
+    context: &Context,
+    interaction: &ApplicationCommandInteraction<'_>,
+    user_id: Option<Id<UserMarker>>,
+    options: RankCardThemeOptions,
+) -> Result<()> {
+    interaction
+        .context
+        .defer(DeferInteractionPayload {
+            ephemeral: true,
+        })
+        .await?;
+
+    let description = match parse_theme(context, options).await? {
+        Err(description) => description,
+        Ok(update) => {
+            context
+                .database
+                .update_rank_card_theme(interaction.cached_guild.guild_id, user_id, update)
+                .await?;
+
+            match user_id {
+                Some(_) => "Your rank card has been updated. Use `/rank` to preview it.",
+                None => "The server's default rank card has been updated.",
+            }
+            .to_owned()
+        }
+    };
+    let embed = EmbedBuilder::new()
+        .color(0xF8F8FF)
+        .description(description)
+        .build();
+
+    interaction
+        .context
+        .update_response(UpdatePayload {
+            embeds: vec![embed],
+            ..Default::default()
+        })
+        .await?;
+
+    Ok(())
+}
+
+pub async fn download_background(
+    context: &Context,
+    background: &Attachment,
+) -> Result<std::result::Result<Vec<u8>, String>> {
+    if background.size > MAX_BACKGROUND_BYTES {
+        return Ok(Err("The background image must be smaller than 8 MB.".to_owned()));
+    }
+
+    let response = context.hyper.get(background.url.parse()?).await?;
+
+    if !response.status().is_success() {
+        return Ok(Err(
+            "The background image could not be downloaded, please try again.".to_owned(),
+        ));
+    }
+
+    let bytes = hyper::body::to_bytes(response.into_body()).await?;
+
+    context.renderer.render(move || fit_background(&bytes)).await
+}
+
+async fn parse_theme(
+    context: &Context,
+    options: RankCardThemeOptions,
+) -> Result<std::result::Result<RankCardThemeUpdate, String>> {
+    let RankCardThemeOptions {
+        accent,
+        background,
+        bar_style,
+        font,
+        opacity,
+    } = options;
+    let accent_color = match accent {
+        None => None,
+        Some(accent) => {
+            let hex = accent.trim().trim_start_matches('#');
+
+            match u32::from_str_radix(hex, 16) {
+                Ok(accent_color) if hex.len() == 6 => Some(accent_color),
+                _ => {
+                    return Ok(Err(format!(
+                        "`{accent}` is not a valid hex color. Use a value like `#C9AD7F`."
+                    )))
+                }
+            }
+        }
+    };
+    let background = match background {
+        None => None,
+        Some(background) => match download_background(context, &background).await? {
+            Ok(background) => Some(background),
+            Err(description) => return Ok(Err(description)),
+        },
+    };
+
+    Ok(Ok(RankCardThemeUpdate {
+        accent_color,
+        background,
+        bar_style: bar_style.map(|bar_style| match bar_style {
+            RankCardBarStyleOption::Rounded => RankCardBarStyle::Rounded,
+            RankCardBarStyleOption::Square => RankCardBarStyle::Square,
+            RankCardBarStyleOption::Thin => RankCardBarStyle::Thin,
+        }),
+        font: font.map(|font| match font {
+            RankCardFontOption::Monospace => RankCardFont::Monospace,
+            RankCardFontOption::Serif => RankCardFont::Serif,
+            RankCardFontOption::SourceSans3 => RankCardFont::SourceSans3,
+        }),
+        overlay_opacity: opacity,
+    }))
+}
@@ -0,0 +1,58 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Reset a rank card theme", name = "reset")]
+pub struct RankCardResetCommand {
+    #[command(desc = "Reset the server's default theme instead of your own")]
+    pub default: Option<bool>,
+}
+
+impl RankCardResetCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: true,
+            })
+            .await?;
+
+        let reset_default = options.default.unwrap_or(false);
+        let user_id = (!reset_default).then_some(interaction.user_id);
+
+        context
+            .database
+            .delete_rank_card_theme(interaction.cached_guild.guild_id, user_id)
+            .await?;
+
+        let description = if reset_default {
+            "The server's default rank card has been reset."
+        } else {
+            "Your rank card now uses the server's default theme."
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+
+use super::{update_theme, RankCardBarStyleOption, RankCardFontOption, RankCardThemeOptions};
+use crate::types::{context::Context, interaction::ApplicationCommandInteraction, Result};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Customize your own rank card in this server", name = "set")]
+pub struct RankCardSetCommand {
+    #[command(desc = "The accent color as a hex code, such as #C9AD7F", max_length = 7)]
+    accent: Option<String>,
+    #[command(desc = "A background image, cropped to 875x250")]
+    background: Option<Attachment>,
+    #[command(desc = "The progress bar style")]
+    bar_style: Option<RankCardBarStyleOption>,
+    #[command(desc = "The font")]
+    font: Option<RankCardFontOption>,
+    #[command(desc = "The overlay opacity as a percentage", max_value = 100, min_value = 0)]
+    opacity: Option<i64>,
+}
+
+impl RankCardSetCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        let Self {
+            accent,
+            background,
+            bar_style,
+            font,
+            opacity,
+        } = options;
+
+        update_theme(
+            context,
+            interaction,
+            Some(interaction.user_id),
+            RankCardThemeOptions {
+                accent,
+                background,
+                bar_style,
+                font,
+                opacity,
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,56 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "End the current season and archive its top members", name = "end")]
+pub struct SeasonEndCommand {}
+
+impl SeasonEndCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let guild_id = interaction.cached_guild.guild_id;
+        let description = match context.database.get_active_season(guild_id).await? {
+            Some(season)
+                if context
+                    .database
+                    .end_season(guild_id, season.season_number, false)
+                    .await? =>
+            {
+                format!(
+                    "Season {0} has ended. View the results with `/leaderboard season:{0}`.",
+                    season.season_number
+                )
+            }
+            _ => "There is no season running.".to_owned(),
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
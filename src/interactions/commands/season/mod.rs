@@ -0,0 +1,34 @@
+mod end;
+mod start;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use self::{end::SeasonEndCommand, start::SeasonStartCommand};
+use crate::types::{context::Context, interaction::ApplicationCommandInteraction, Result};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Manage competitive seasons", name = "season")]
+pub enum SeasonCommand {
+    #[command(name = "end")]
+    End(SeasonEndCommand),
+    #[command(name = "start")]
+    Start(SeasonStartCommand),
+}
+
+impl SeasonCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        if interaction.require_administrator("use this command").await? {
+            match SeasonCommand::from_interaction(interaction.input_data())? {
+                SeasonCommand::End(_) => SeasonEndCommand::run(context, interaction).await?,
+                SeasonCommand::Start(options) => {
+                    SeasonStartCommand::run(context, interaction, options).await?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
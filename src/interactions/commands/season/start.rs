@@ -0,0 +1,88 @@
+use time::{ext::NumericalDuration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Start a new season", name = "start")]
+pub struct SeasonStartCommand {
+    #[command(
+        desc = "How many top members to archive when the season ends",
+        max_value = 100,
+        min_value = 1
+    )]
+    archive_top: Option<i64>,
+    #[command(
+        desc = "Days each season lasts before the next one starts, or leave empty to end it manually",
+        max_value = 365,
+        min_value = 1
+    )]
+    duration_days: Option<i64>,
+}
+
+impl SeasonStartCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            archive_top,
+            duration_days,
+        } = options;
+        let archive_size = archive_top.unwrap_or(10);
+        let ends_at = duration_days.map(|days| OffsetDateTime::now_utc() + days.days());
+        let season_number = context
+            .database
+            .insert_season(
+                interaction.cached_guild.guild_id,
+                ends_at,
+                archive_size,
+                duration_days,
+            )
+            .await?;
+        let description = match (season_number, ends_at) {
+            (None, _) => {
+                "A season is already running. End it with `/season end` before starting a new \
+                 one."
+                    .to_owned()
+            }
+            (Some(season_number), Some(ends_at)) => format!(
+                "Season {season_number} has started and ends <t:{}:R>. The top {archive_size} \
+                 member(s) will be archived when it ends, and a new season of the same length \
+                 will start until `/season end` is used.",
+                ends_at.unix_timestamp()
+            ),
+            (Some(season_number), None) => format!(
+                "Season {season_number} has started and runs until `/season end` is used. The \
+                 top {archive_size} member(s) will be archived when it ends."
+            ),
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::{format_xp_update, get_member_xp, set_member_xp};
+use crate::types::{
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Give experience to a member", name = "give")]
+pub struct XpGiveCommand {
+    #[command(desc = "The amount of XP to give", max_value = 1_000_000, min_value = 1)]
+    amount: i64,
+    #[command(desc = "The member to give experience to", rename = "member")]
+    user_id: Id<UserMarker>,
+}
+
+impl XpGiveCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            amount,
+            user_id,
+        } = options;
+        let xp = get_member_xp(context, &interaction.cached_guild, user_id)
+            .await?
+            .saturating_add(amount);
+
+        let in_guild = set_member_xp(context, &interaction.cached_guild, user_id, xp).await?;
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format_xp_update(
+                format!("Gave {amount} XP to <@{user_id}>, who now has {xp} XP."),
+                user_id,
+                in_guild,
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
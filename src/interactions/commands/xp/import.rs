@@ -0,0 +1,385 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use serde_json::Value;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::Attachment,
+    id::{marker::UserMarker, Id},
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::MemberUpdate,
+        context::Context,
+        interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+        Result,
+    },
+    utility::leveling::xp_for_level,
+};
+
+#[derive(Debug, PartialEq)]
+enum ImportValue {
+    Level(u64),
+    Xp(i64),
+}
+
+struct ImportRows {
+    invalid: usize,
+    rows: Vec<(Id<UserMarker>, ImportValue)>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Import experience from a CSV file or another leveling bot's export",
+    name = "import"
+)]
+pub struct XpImportCommand {
+    #[command(desc = "A CSV of user_id,xp or user_id,level, or a MEE6 or Arcane JSON export")]
+    file: Attachment,
+}
+
+impl XpImportCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            file,
+        } = options;
+        let import_rows = if file.size > 8_000_000 {
+            Err("The uploaded file must be smaller than 8 MB.".to_owned())
+        } else {
+            let response = context.hyper.get(file.url.parse()?).await?;
+            let bytes = hyper::body::to_bytes(response.into_body()).await?;
+
+            parse_import(&bytes)
+        };
+        let description = match import_rows {
+            Err(description) => description,
+            Ok(ImportRows {
+                invalid,
+                rows,
+            }) => {
+                let guild = &interaction.cached_guild;
+                let level_curve = guild.level_curve.read().clone();
+                let mut members = HashMap::new();
+                let mut skipped = 0;
+
+                for (user_id, value) in rows {
+                    let xp = match value {
+                        ImportValue::Level(level) => xp_for_level(&level_curve, level),
+                        ImportValue::Xp(xp) => xp,
+                    };
+
+                    if context
+                        .cache
+                        .get_member(guild.guild_id, user_id)
+                        .map_or(false, |member| member.bot)
+                    {
+                        skipped += 1;
+
+                        continue;
+                    }
+
+                    match members.entry(user_id) {
+                        Entry::Occupied(_) => skipped += 1,
+                        Entry::Vacant(entry) => {
+                            entry.insert(xp);
+                        }
+                    }
+                }
+
+                let members = members.into_iter().collect::<Vec<(Id<UserMarker>, i64)>>();
+
+                context
+                    .database
+                    .insert_members_xp(guild.guild_id, &members)
+                    .await?;
+
+                for (user_id, xp) in members.iter() {
+                    context.cache.update_member(
+                        guild.guild_id,
+                        *user_id,
+                        MemberUpdate {
+                            xp: Some(*xp),
+                            ..Default::default()
+                        },
+                    );
+                }
+
+                format!(
+                    "Imported {} member(s), skipped {skipped} and found {invalid} invalid \
+                     row(s).\nUse `/config sync-roles` to give imported members their level roles.",
+                    members.len()
+                )
+            }
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn parse_import(bytes: &[u8]) -> std::result::Result<ImportRows, String> {
+    let text = String::from_utf8_lossy(bytes);
+    let text = text.trim_start_matches('\u{feff}').trim();
+
+    if text.starts_with('{') || text.starts_with('[') {
+        parse_json(text)
+    } else {
+        parse_csv(text)
+    }
+}
+
+fn parse_csv(text: &str) -> std::result::Result<ImportRows, String> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty()).peekable();
+    let Some(first_line) = lines.peek() else {
+        return Err("The uploaded file is empty.".to_owned());
+    };
+    let first_fields = split_csv_line(first_line);
+    let (id_index, value_index, is_level) = if first_fields
+        .first()
+        .map_or(false, |field| field.parse::<u64>().is_ok())
+    {
+        (0, 1, false)
+    } else {
+        let find = |names: &[&str]| {
+            first_fields
+                .iter()
+                .position(|field| names.contains(&field.to_lowercase().as_str()))
+        };
+        let Some(id_index) = find(&["id", "user", "user_id", "userid"]) else {
+            return Err("The CSV header must contain a user_id column.".to_owned());
+        };
+
+        lines.next();
+
+        match (find(&["xp", "exp", "experience"]), find(&["level", "lvl"])) {
+            (Some(xp_index), _) => (id_index, xp_index, false),
+            (None, Some(level_index)) => (id_index, level_index, true),
+            (None, None) => {
+                return Err("The CSV header must contain an xp or level column.".to_owned())
+            }
+        }
+    };
+    let mut import_rows = ImportRows {
+        invalid: 0,
+        rows: Vec::new(),
+    };
+
+    for line in lines {
+        let fields = split_csv_line(line);
+        let user_id = fields
+            .get(id_index)
+            .and_then(|field| field.parse::<u64>().ok())
+            .and_then(Id::new_checked);
+        let value = fields.get(value_index).and_then(|field| {
+            if is_level {
+                field.parse::<u64>().ok().map(ImportValue::Level)
+            } else {
+                field
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|xp| xp.ge(&0))
+                    .map(ImportValue::Xp)
+            }
+        });
+
+        match (user_id, value) {
+            (Some(user_id), Some(value)) => import_rows.rows.push((user_id, value)),
+            _ => import_rows.invalid += 1,
+        }
+    }
+
+    Ok(import_rows)
+}
+
+fn parse_json(text: &str) -> std::result::Result<ImportRows, String> {
+    let value = serde_json::from_str::<Value>(text)
+        .map_err(|_| "The uploaded file is not valid JSON.".to_owned())?;
+    let entries = match &value {
+        Value::Array(entries) => entries,
+        Value::Object(object) => match ["players", "members", "users", "leaderboard"]
+            .iter()
+            .find_map(|key| object.get(*key).and_then(Value::as_array))
+        {
+            Some(entries) => entries,
+            None => return Err("The uploaded JSON does not contain a list of members.".to_owned()),
+        },
+        _ => return Err("The uploaded JSON does not contain a list of members.".to_owned()),
+    };
+    let mut import_rows = ImportRows {
+        invalid: 0,
+        rows: Vec::new(),
+    };
+
+    for entry in entries {
+        let user_id = ["id", "user_id", "userId", "user"]
+            .iter()
+            .find_map(|key| entry.get(*key))
+            .and_then(json_u64)
+            .and_then(Id::new_checked);
+        let value = match ["xp", "exp", "experience"]
+            .iter()
+            .find_map(|key| entry.get(*key))
+        {
+            Some(xp) => json_u64(xp).map(|xp| ImportValue::Xp(xp.min(i64::MAX as u64) as i64)),
+            None => entry
+                .get("level")
+                .and_then(json_u64)
+                .map(ImportValue::Level),
+        };
+
+        match (user_id, value) {
+            (Some(user_id), Some(value)) => import_rows.rows.push((user_id, value)),
+            _ => import_rows.invalid += 1,
+        }
+    }
+
+    Ok(import_rows)
+}
+
+fn json_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(number) => number.as_u64().or_else(|| {
+            number
+                .as_f64()
+                .filter(|number| number.ge(&0.0))
+                .map(|number| number as u64)
+        }),
+        Value::String(string) => string.trim().parse::<u64>().ok(),
+        _ => None,
+    }
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut characters = line.chars().peekable();
+    let mut field = String::new();
+    let mut fields = Vec::new();
+    let mut quoted = false;
+
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if quoted && characters.peek().eq(&Some(&'"')) => {
+                characters.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' | ';' | '\t' if !quoted => fields.push(std::mem::take(&mut field).trim().to_owned()),
+            _ => field.push(character),
+        }
+    }
+
+    fields.push(field.trim().to_owned());
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::Id;
+
+    use super::{parse_import, split_csv_line, ImportRows, ImportValue};
+
+    fn parse(text: &str) -> ImportRows {
+        parse_import(text.as_bytes()).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    #[test]
+    fn split_csv_line_handles_quoted_fields() {
+        assert_eq!(split_csv_line("\"123\", 500"), vec!["123", "500"]);
+        assert_eq!(
+            split_csv_line("123,\"Smith, \"\"Jo\"\"\";500"),
+            vec!["123", "Smith, \"Jo\"", "500"]
+        );
+        assert_eq!(split_csv_line("123\t\t500"), vec!["123", "", "500"]);
+    }
+
+    #[test]
+    fn parse_csv_handles_header() {
+        let import_rows =
+            parse("user_id,username,xp\n\"123\",\"Smith, John\",500\n456,bob,-1\n789,alice,abc");
+
+        assert_eq!(import_rows.invalid, 2);
+        assert_eq!(import_rows.rows, vec![(Id::new(123), ImportValue::Xp(500))]);
+    }
+
+    #[test]
+    fn parse_csv_handles_level_header() {
+        let import_rows = parse("\u{feff}\"User\";\"Level\"\n123;5\n\n456;7");
+
+        assert_eq!(import_rows.invalid, 0);
+        assert_eq!(
+            import_rows.rows,
+            vec![(Id::new(123), ImportValue::Level(5)), (Id::new(456), ImportValue::Level(7))]
+        );
+    }
+
+    #[test]
+    fn parse_csv_handles_missing_header_columns() {
+        assert!(parse_import(b"username,xp\nbob,500").is_err());
+        assert!(parse_import(b"user_id,username\n123,bob").is_err());
+    }
+
+    #[test]
+    fn parse_csv_handles_no_header() {
+        let import_rows = parse("\"123\",\"500\"\n456,700\n0,100");
+
+        assert_eq!(import_rows.invalid, 1);
+        assert_eq!(
+            import_rows.rows,
+            vec![(Id::new(123), ImportValue::Xp(500)), (Id::new(456), ImportValue::Xp(700))]
+        );
+    }
+
+    #[test]
+    fn parse_json_handles_array() {
+        let import_rows =
+            parse(r#"[{"id": "123", "xp": 500}, {"user_id": 456, "level": 7}, {"xp": 100}]"#);
+
+        assert_eq!(import_rows.invalid, 1);
+        assert_eq!(
+            import_rows.rows,
+            vec![(Id::new(123), ImportValue::Xp(500)), (Id::new(456), ImportValue::Level(7))]
+        );
+    }
+
+    #[test]
+    fn parse_json_handles_object_keys() {
+        for key in ["players", "members", "users", "leaderboard"] {
+            let import_rows = parse(&format!(
+                r#"{{"page": 0, "{key}": [{{"userId": "123", "experience": 500.0}}]}}"#
+            ));
+
+            assert_eq!(import_rows.invalid, 0, "{key}");
+            assert_eq!(import_rows.rows, vec![(Id::new(123), ImportValue::Xp(500))], "{key}");
+        }
+    }
+
+    #[test]
+    fn parse_json_handles_missing_list() {
+        assert!(parse_import(br#"{"guild": {"id": "123"}}"#).is_err());
+        assert!(parse_import(b"[1, 2").is_err());
+    }
+}
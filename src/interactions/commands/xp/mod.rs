@@ -0,0 +1,186 @@
+mod give;
+mod import;
+mod reset;
+mod set;
+mod take;
+
+use std::collections::HashSet;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{
+    marker::{RoleMarker, UserMarker},
+    Id,
+};
+
+use self::{
+    give::XpGiveCommand,
+    import::XpImportCommand,
+    reset::XpResetCommand,
+    set::XpSetCommand,
+    take::XpTakeCommand,
+};
+use crate::{
+    types::{
+        cache::{Guild, MemberUpdate},
+        context::Context,
+        interaction::ApplicationCommandInteraction,
+        Result,
+    },
+    utility::leveling::{level_for_xp, member_role_ids},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Manage member experience", name = "xp")]
+pub enum XpCommand {
+    #[command(name = "give")]
+    Give(XpGiveCommand),
+    #[command(name = "import")]
+    Import(XpImportCommand),
+    #[command(name = "reset")]
+    Reset(XpResetCommand),
+    #[command(name = "set")]
+    Set(XpSetCommand),
+    #[command(name = "take")]
+    Take(XpTakeCommand),
+}
+
+impl XpCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        if interaction.require_administrator("use this command").await? {
+            match XpCommand::from_interaction(interaction.input_data())? {
+                XpCommand::Give(options) => {
+                    XpGiveCommand::run(context, interaction, options).await?
+                }
+                XpCommand::Import(options) => {
+                    XpImportCommand::run(context, interaction, options).await?
+                }
+                XpCommand::Reset(options) => {
+                    XpResetCommand::run(context, interaction, options).await?
+                }
+                XpCommand::Set(options) => {
+                    XpSetCommand::run(context, interaction, options).await?
+                }
+                XpCommand::Take(options) => {
+                    XpTakeCommand::run(context, interaction, options).await?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_xp_update(
+    description: String,
+    user_id: Id<UserMarker>,
+    in_guild: bool,
+) -> String {
+    if in_guild {
+        description
+    } else {
+        format!(
+            "{description}\n<@{user_id}> is no longer in this server, so only their stored XP was \
+             changed."
+        )
+    }
+}
+
+async fn get_member_xp(
+    context: &Context,
+    guild: &Guild,
+    user_id: Id<UserMarker>,
+) -> Result<i64> {
+    if let Some(member) = context.cache.get_member(guild.guild_id, user_id) {
+        return Ok(*member.xp.read());
+    }
+
+    let (xp, _) = context
+        .database
+        .get_member(guild.guild_id, user_id)
+        .await?
+        .unwrap_or_default();
+
+    Ok(xp)
+}
+
+async fn set_member_xp(
+    context: &Context,
+    guild: &Guild,
+    user_id: Id<UserMarker>,
+    xp: i64,
+) -> Result<bool> {
+    let xp = xp.max(0);
+    let current_role_ids = match context.cache.get_member(guild.guild_id, user_id) {
+        Some(member) => Some(member.role_ids.read().clone()),
+        None => match context.http.guild_member(guild.guild_id, user_id).await {
+            Ok(response) => Some(HashSet::from_iter(response.model().await?.roles)),
+            Err(_) => None,
+        },
+    };
+    let Some(current_role_ids) = current_role_ids else {
+        context
+            .database
+            .update_member_xp(guild.guild_id, user_id, xp, None)
+            .await?;
+
+        return Ok(false);
+    };
+    let role_ids = update_level_roles(context, guild, user_id, &current_role_ids, xp).await?;
+
+    context
+        .database
+        .update_member_xp(guild.guild_id, user_id, xp, None)
+        .await?;
+    context.cache.update_member(
+        guild.guild_id,
+        user_id,
+        MemberUpdate {
+            role_ids: Some(role_ids),
+            xp: Some(xp),
+            ..Default::default()
+        },
+    );
+
+    Ok(true)
+}
+
+async fn update_level_roles(
+    context: &Context,
+    guild: &Guild,
+    user_id: Id<UserMarker>,
+    current_role_ids: &HashSet<Id<RoleMarker>>,
+    xp: i64,
+) -> Result<HashSet<Id<RoleMarker>>> {
+    let level = level_for_xp(&guild.level_curve.read(), xp);
+    let levels = guild.levels.read().clone();
+    let mut earned_role_ids = current_role_ids.clone();
+
+    for (_, level_role_ids) in levels
+        .iter()
+        .filter(|(role_level, _)| role_level.gt(&level))
+    {
+        for role_id in level_role_ids.iter() {
+            earned_role_ids.remove(role_id);
+        }
+    }
+
+    let role_ids = member_role_ids(
+        &earned_role_ids,
+        &levels,
+        level,
+        *guild.role_stacking.read(),
+    );
+
+    if role_ids.ne(current_role_ids) {
+        context
+            .http
+            .update_guild_member(guild.guild_id, user_id)
+            .roles(&role_ids.iter().copied().collect::<Vec<Id<RoleMarker>>>())
+            .await?;
+    }
+
+    Ok(role_ids)
+}
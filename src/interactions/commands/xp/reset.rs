@@ -0,0 +1,119 @@
+use std::time::Instant;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::{format_xp_update, set_member_xp, update_level_roles};
+use crate::types::{
+    cache::MemberUpdate,
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Reset the experience of a member or the whole guild", name = "reset")]
+pub struct XpResetCommand {
+    #[command(desc = "Whether to reset every member in the guild")]
+    all: Option<bool>,
+    #[command(desc = "The member to reset", rename = "member")]
+    user_id: Option<Id<UserMarker>>,
+}
+
+impl XpResetCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let started_at = Instant::now();
+        let Self {
+            all,
+            user_id,
+        } = options;
+        let guild = &interaction.cached_guild;
+        let description = match (user_id, all.unwrap_or(false)) {
+            (Some(user_id), _) => {
+                let in_guild = set_member_xp(context, guild, user_id, 0).await?;
+
+                format_xp_update(
+                    format!("The experience of <@{user_id}> has been reset."),
+                    user_id,
+                    in_guild,
+                )
+            }
+            (None, true) => {
+                context.database.reset_guild_xp(guild.guild_id).await?;
+
+                let member_ids = guild.member_ids.read().iter().copied().collect::<Vec<_>>();
+                let mut failed = 0;
+
+                for user_id in member_ids.iter() {
+                    let Some(member) = context.cache.get_member(guild.guild_id, *user_id) else {
+                        continue;
+                    };
+                    let current_role_ids = member.role_ids.read().clone();
+                    let role_ids = match update_level_roles(
+                        context,
+                        guild,
+                        *user_id,
+                        &current_role_ids,
+                        0,
+                    )
+                    .await
+                    {
+                        Ok(role_ids) => role_ids,
+                        Err(_) => {
+                            failed += 1;
+
+                            current_role_ids
+                        }
+                    };
+
+                    context.cache.update_member(
+                        guild.guild_id,
+                        *user_id,
+                        MemberUpdate {
+                            role_ids: Some(role_ids),
+                            xp: Some(0),
+                            ..Default::default()
+                        },
+                    );
+                }
+
+                let mut description = "The experience of every member has been reset.".to_owned();
+
+                if failed.gt(&0) {
+                    description.push_str(&format!(
+                        "\nThe level roles of {failed} member(s) could not be updated."
+                    ));
+                }
+
+                description
+            }
+            (None, false) => {
+                "Choose a member to reset, or set `all` to reset every member in the guild."
+                    .to_owned()
+            }
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .update_progress(context, &mut None, started_at, embed)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::{format_xp_update, set_member_xp};
+use crate::types::{
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdatePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Set a member's experience", name = "set")]
+pub struct XpSetCommand {
+    #[command(desc = "The amount of XP to set", max_value = 100_000_000, min_value = 0)]
+    amount: i64,
+    #[command(desc = "The member to set the experience of", rename = "member")]
+    user_id: Id<UserMarker>,
+}
+
+impl XpSetCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Self {
+            amount,
+            user_id,
+        } = options;
+        let in_guild = set_member_xp(context, &interaction.cached_guild, user_id, amount).await?;
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format_xp_update(
+                format!("<@{user_id}> now has {amount} XP."),
+                user_id,
+                in_guild,
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdatePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,16 +1,18 @@
-use std::sync::Arc;
-
-use thousands::Separable;
-use twilight_util::builder::embed::{EmbedBuilder, EmbedFooterBuilder};
+use time::OffsetDateTime;
+use twilight_util::{builder::embed::EmbedBuilder, snowflake::Snowflake};
 
 use crate::{
+    interactions::commands::leaderboard::{
+        format_leaderboard_message,
+        get_leaderboard,
+        LeaderboardView,
+    },
     types::{
-        cache::Member,
         context::Context,
-        interaction::{MessageComponentInteraction, UpdatePayload},
+        interaction::{MessageComponentInteraction, ResponsePayload, UpdatePayload},
         Result,
     },
-    utility::decimal::modulo,
+    utility::{constants::LEADERBOARD_BUTTON_EXPIRY_SECONDS, decimal::modulo},
 };
 
 pub struct LeaderboardComponent {}
@@ -20,88 +22,62 @@ impl LeaderboardComponent {
         context: &Context,
         interaction: &MessageComponentInteraction<'_>,
     ) -> Result<()> {
-        let footer_text = &interaction.message.embeds[0].footer.as_ref().unwrap().text;
-        let mut split = footer_text.split(" ");
-        let current_index = split.nth(1).unwrap().parse::<usize>()? - 1;
-        let mut leaderboard = interaction
-            .cached_guild
-            .member_ids
-            .read()
-            .iter()
-            .filter_map(|user_id| {
-                let member = context
-                    .cache
-                    .get_member(interaction.cached_guild.guild_id, *user_id);
+        let message_age_seconds = (OffsetDateTime::now_utc().unix_timestamp() * 1_000
+            - interaction.message.id.timestamp())
+            / 1_000;
 
-                match member {
-                    Some(member) if member.xp.read().gt(&0) => Some(member),
-                    _ => None,
-                }
-            })
-            .collect::<Vec<Arc<Member>>>();
-
-        leaderboard.sort_unstable_by(|a, b| {
-            if !b.xp.read().eq(&*a.xp.read()) {
-                b.xp.read().cmp(&a.xp.read())
-            } else if !b
-                .last_message_timestamp
-                .read()
-                .eq(&a.last_message_timestamp.read())
-            {
-                b.last_message_timestamp
-                    .read()
-                    .cmp(&a.last_message_timestamp.read())
-            } else {
-                b.joined_voice_timestamp
-                    .read()
-                    .cmp(&a.joined_voice_timestamp.read())
-            }
-        });
+        if message_age_seconds.gt(&LEADERBOARD_BUTTON_EXPIRY_SECONDS) {
+            return interaction.context.clear_components().await;
+        }
 
-        let total_pages = (leaderboard.len() as f32 / 10.0).ceil() as usize;
-        let new_index = if interaction.data.custom_id.as_str().ends_with("next") {
-            modulo(total_pages + current_index + 1, total_pages)
-        } else {
-            modulo(total_pages + current_index - 1, total_pages)
-        };
-        let embed = EmbedBuilder::new()
-            .color(0xF8F8FF)
-            .description(
-                leaderboard
+        let (name, current_index, view) =
+            LeaderboardView::from_custom_id(&interaction.data.custom_id);
+        let leaderboard = get_leaderboard(context, &interaction.cached_guild, view).await?;
+        let total_pages = ((leaderboard.len() as f32 / 10.0).ceil() as usize).max(1);
+        let current_index = current_index.min(total_pages - 1);
+        let new_index = match name {
+            "leaderboard-first" => 0,
+            "leaderboard-last" => total_pages - 1,
+            "leaderboard-me" => {
+                let Some(position) = leaderboard
                     .iter()
-                    .skip(new_index * 10)
-                    .take(10)
-                    .enumerate()
-                    .map(|(index, member)| {
-                        let rank = (new_index * 10) + index + 1;
-                        let username = if member.discriminator == 0 {
-                            member.username.clone()
-                        } else {
-                            format!("{}#{:04}", member.username, member.discriminator)
-                        };
+                    .position(|(member, _)| member.user_id.eq(&interaction.user_id))
+                else {
+                    let embed = EmbedBuilder::new()
+                        .color(0xF8F8FF)
+                        .description("You are not on this leaderboard.")
+                        .build();
+
+                    return interaction
+                        .context
+                        .respond(ResponsePayload {
+                            embeds: vec![embed],
+                            ephemeral: true,
+                            ..Default::default()
+                        })
+                        .await;
+                };
 
-                        format!(
-                            "#{} - **{}** ({} XP)",
-                            rank,
-                            username,
-                            member.xp.read().separate_with_commas()
-                        )
-                    })
-                    .collect::<Vec<String>>()
-                    .join("\n"),
-            )
-            .footer(EmbedFooterBuilder::new(format!(
-                "Page {} of {total_pages}",
-                new_index + 1
-            )))
-            .title(format!("{} leaderboard", interaction.cached_guild.name))
-            .build();
+                position / 10
+            }
+            "leaderboard-next" => modulo(total_pages + current_index + 1, total_pages),
+            _ => modulo(total_pages + current_index - 1, total_pages),
+        };
+        let level_curve = interaction.cached_guild.level_curve.read().clone();
+        let (embed, components) = format_leaderboard_message(
+            &interaction.cached_guild.name,
+            &level_curve,
+            &leaderboard,
+            new_index,
+            view,
+            interaction.user_id,
+        );
 
         interaction
             .context
             .update_message(UpdatePayload {
-                components: interaction.message.components.clone(),
-                embeds: vec![embed.clone()],
+                components,
+                embeds: vec![embed],
                 ..Default::default()
             })
             .await?;
@@ -17,12 +17,15 @@ use crate::{
     utility::{
         constants::BOT_TOKEN,
         gateway::{connect, reconnect},
+        season::close_expired_seasons,
+        voice::checkpoint_voice_sessions,
     },
 };
 
 #[tokio::main]
 async fn main() -> types::Result<()> {
     dotenv().ok();
+    tracing_subscriber::fmt::init();
 
     let http = Client::new(BOT_TOKEN.to_owned());
     let application = http.current_user_application().await?.model().await?;
@@ -41,6 +44,9 @@ async fn main() -> types::Result<()> {
         .set_global_commands(&commands)
         .await?;
 
+    tokio::spawn(checkpoint_voice_sessions(Arc::clone(&context)));
+    tokio::spawn(close_expired_seasons(Arc::clone(&context)));
+
     'outer: loop {
         let mut stream = ShardEventStream::new(shards.iter_mut());
 
@@ -39,6 +39,7 @@ impl Cache {
             Arc::new(Channel {
                 channel_id: channel.id,
                 guild_id,
+                kind: channel.kind,
                 user_ids: RwLock::new(HashSet::new()),
             }),
         );
@@ -79,6 +80,7 @@ impl Cache {
             Arc::new(Channel {
                 channel_id: current_channel.channel_id,
                 guild_id: current_channel.guild_id,
+                kind: current_channel.kind,
                 user_ids: RwLock::new(update.user_ids.unwrap_or(current_channel_user_ids)),
             }),
         );
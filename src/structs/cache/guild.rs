@@ -1,4 +1,7 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use parking_lot::RwLock;
 use time::OffsetDateTime;
@@ -10,7 +13,10 @@ use twilight_model::{
     },
 };
 
-use crate::types::cache::{Cache, Guild, GuildUpdate};
+use crate::types::{
+    cache::{Cache, Guild, GuildUpdate},
+    database::{GuildSettings, MultiplierMode},
+};
 
 impl Cache {
     pub fn get_guild(
@@ -24,8 +30,13 @@ impl Cache {
 
     pub fn insert_guild(
         &self,
+        afk_channel_id: Option<Id<ChannelMarker>>,
+        channel_multipliers: HashMap<Id<ChannelMarker>, f64>,
         channels: Vec<TwilightChannel>,
         guild_id: Id<GuildMarker>,
+        ignored_role_ids: HashSet<Id<RoleMarker>>,
+        ignored_text_channel_ids: HashSet<Id<ChannelMarker>>,
+        ignored_voice_channel_ids: HashSet<Id<ChannelMarker>>,
         levels: Vec<(u64, HashSet<Id<RoleMarker>>)>,
         members: Vec<(
             String,
@@ -39,7 +50,9 @@ impl Cache {
             i64,
         )>,
         name: String,
-        xp_multiplier: f64,
+        role_multipliers: HashMap<Id<RoleMarker>, f64>,
+        role_names: HashMap<Id<RoleMarker>, String>,
+        settings: GuildSettings,
     ) {
         let mut channel_ids: HashSet<Id<ChannelMarker>> = HashSet::new();
         let mut member_ids: HashSet<Id<UserMarker>> = HashSet::new();
@@ -64,7 +77,14 @@ impl Cache {
         {
             member_ids.insert(user_id);
 
-            let joined_voice_timestamp = voice_channel_id.map(|_| OffsetDateTime::now_utc());
+            let accruing_channel_id = voice_channel_id.filter(|_| !bot);
+            let joined_voice_timestamp = accruing_channel_id.map(|_| OffsetDateTime::now_utc());
+
+            if let Some(channel) =
+                accruing_channel_id.and_then(|channel_id| self.get_channel(channel_id))
+            {
+                channel.user_ids.write().insert(user_id);
+            }
 
             self.insert_member(
                 avatar,
@@ -84,12 +104,35 @@ impl Cache {
         self.guilds.write().insert(
             guild_id,
             Arc::new(Guild {
+                afk_channel_id: RwLock::new(afk_channel_id),
                 channel_ids: RwLock::new(channel_ids),
+                channel_multipliers: RwLock::new(channel_multipliers),
+                cooldown_seconds: RwLock::new(settings.cooldown_seconds),
                 guild_id,
+                ignored_role_ids: RwLock::new(ignored_role_ids),
+                ignored_text_channel_ids: RwLock::new(ignored_text_channel_ids),
+                ignored_voice_channel_ids: RwLock::new(ignored_voice_channel_ids),
+                level_curve: RwLock::new(settings.level_curve),
+                level_up_channel_id: RwLock::new(settings.level_up_channel_id),
+                level_up_mode: RwLock::new(settings.level_up_mode),
+                level_up_template: RwLock::new(settings.level_up_template),
                 levels: RwLock::new(levels),
+                max_xp: RwLock::new(settings.max_xp),
                 member_ids: RwLock::new(member_ids),
+                min_xp: RwLock::new(settings.min_xp),
+                multiplier_mode: RwLock::new(settings.multiplier_mode),
                 name,
-                xp_multiplier: RwLock::new(xp_multiplier),
+                role_multipliers: RwLock::new(role_multipliers),
+                role_names: RwLock::new(role_names),
+                role_stacking: RwLock::new(settings.role_stacking),
+                voice_allow_muted: RwLock::new(settings.voice_allow_muted),
+                voice_audience_multiplier: RwLock::new(settings.voice_audience_multiplier),
+                voice_bonus_multiplier: RwLock::new(settings.voice_bonus_multiplier),
+                voice_daily_xp_cap: RwLock::new(settings.voice_daily_xp_cap),
+                voice_max_session_xp: RwLock::new(settings.voice_max_session_xp),
+                voice_min_members: RwLock::new(settings.voice_min_members),
+                voice_xp_per_minute: RwLock::new(settings.voice_xp_per_minute),
+                xp_multiplier: RwLock::new(settings.xp_multiplier),
             }),
         );
         self.remove_unavailable_guild(guild_id)
@@ -129,19 +172,145 @@ impl Cache {
         let Some(current_guild) = self.get_guild(guild_id) else {
             return
         };
+        let current_guild_afk_channel_id = current_guild.afk_channel_id.read().clone();
         let current_guild_channel_ids = current_guild.channel_ids.read().clone();
+        let current_guild_channel_multipliers = current_guild.channel_multipliers.read().clone();
+        let current_guild_cooldown_seconds = current_guild.cooldown_seconds.read().clone();
+        let current_guild_ignored_role_ids = current_guild.ignored_role_ids.read().clone();
+        let current_guild_ignored_text_channel_ids =
+            current_guild.ignored_text_channel_ids.read().clone();
+        let current_guild_ignored_voice_channel_ids =
+            current_guild.ignored_voice_channel_ids.read().clone();
+        let current_guild_level_curve = current_guild.level_curve.read().clone();
+        let current_guild_level_up_channel_id = current_guild.level_up_channel_id.read().clone();
+        let current_guild_level_up_mode = current_guild.level_up_mode.read().clone();
+        let current_guild_level_up_template = current_guild.level_up_template.read().clone();
         let current_guild_levels = current_guild.levels.read().clone();
+        let current_guild_max_xp = current_guild.max_xp.read().clone();
         let current_guild_member_ids = current_guild.member_ids.read().clone();
+        let current_guild_min_xp = current_guild.min_xp.read().clone();
+        let current_guild_multiplier_mode = current_guild.multiplier_mode.read().clone();
+        let current_guild_role_multipliers = current_guild.role_multipliers.read().clone();
+        let current_guild_role_names = current_guild.role_names.read().clone();
+        let current_guild_role_stacking = current_guild.role_stacking.read().clone();
+        let current_guild_voice_allow_muted = current_guild.voice_allow_muted.read().clone();
+        let current_guild_voice_audience_multiplier =
+            current_guild.voice_audience_multiplier.read().clone();
+        let current_guild_voice_bonus_multiplier =
+            current_guild.voice_bonus_multiplier.read().clone();
+        let current_guild_voice_daily_xp_cap = current_guild.voice_daily_xp_cap.read().clone();
+        let current_guild_voice_max_session_xp = current_guild.voice_max_session_xp.read().clone();
+        let current_guild_voice_min_members = current_guild.voice_min_members.read().clone();
+        let current_guild_voice_xp_per_minute = current_guild.voice_xp_per_minute.read().clone();
         let current_guild_xp_multiplier = current_guild.xp_multiplier.read().clone();
 
         self.guilds.write().insert(
             guild_id,
             Arc::new(Guild {
+                afk_channel_id: RwLock::new(
+                    update
+                        .afk_channel_id
+                        .unwrap_or(current_guild_afk_channel_id),
+                ),
                 channel_ids: RwLock::new(update.channel_ids.unwrap_or(current_guild_channel_ids)),
+                channel_multipliers: RwLock::new(
+                    update
+                        .channel_multipliers
+                        .unwrap_or(current_guild_channel_multipliers),
+                ),
+                cooldown_seconds: RwLock::new(
+                    update
+                        .cooldown_seconds
+                        .unwrap_or(current_guild_cooldown_seconds),
+                ),
                 guild_id,
+                ignored_role_ids: RwLock::new(
+                    update
+                        .ignored_role_ids
+                        .unwrap_or(current_guild_ignored_role_ids),
+                ),
+                ignored_text_channel_ids: RwLock::new(
+                    update
+                        .ignored_text_channel_ids
+                        .unwrap_or(current_guild_ignored_text_channel_ids),
+                ),
+                ignored_voice_channel_ids: RwLock::new(
+                    update
+                        .ignored_voice_channel_ids
+                        .unwrap_or(current_guild_ignored_voice_channel_ids),
+                ),
+                level_curve: RwLock::new(update.level_curve.unwrap_or(current_guild_level_curve)),
+                level_up_channel_id: RwLock::new(
+                    update
+                        .level_up_channel_id
+                        .unwrap_or(current_guild_level_up_channel_id),
+                ),
+                level_up_mode: RwLock::new(
+                    update
+                        .level_up_mode
+                        .unwrap_or(current_guild_level_up_mode),
+                ),
+                level_up_template: RwLock::new(
+                    update
+                        .level_up_template
+                        .unwrap_or(current_guild_level_up_template),
+                ),
                 levels: RwLock::new(update.levels.unwrap_or(current_guild_levels)),
+                max_xp: RwLock::new(update.max_xp.unwrap_or(current_guild_max_xp)),
                 member_ids: RwLock::new(update.member_ids.unwrap_or(current_guild_member_ids)),
+                min_xp: RwLock::new(update.min_xp.unwrap_or(current_guild_min_xp)),
+                multiplier_mode: RwLock::new(
+                    update
+                        .multiplier_mode
+                        .unwrap_or(current_guild_multiplier_mode),
+                ),
                 name: update.name.unwrap_or(current_guild.name.clone()),
+                role_multipliers: RwLock::new(
+                    update
+                        .role_multipliers
+                        .unwrap_or(current_guild_role_multipliers),
+                ),
+                role_names: RwLock::new(update.role_names.unwrap_or(current_guild_role_names)),
+                role_stacking: RwLock::new(
+                    update
+                        .role_stacking
+                        .unwrap_or(current_guild_role_stacking),
+                ),
+                voice_allow_muted: RwLock::new(
+                    update
+                        .voice_allow_muted
+                        .unwrap_or(current_guild_voice_allow_muted),
+                ),
+                voice_audience_multiplier: RwLock::new(
+                    update
+                        .voice_audience_multiplier
+                        .unwrap_or(current_guild_voice_audience_multiplier),
+                ),
+                voice_bonus_multiplier: RwLock::new(
+                    update
+                        .voice_bonus_multiplier
+                        .unwrap_or(current_guild_voice_bonus_multiplier),
+                ),
+                voice_daily_xp_cap: RwLock::new(
+                    update
+                        .voice_daily_xp_cap
+                        .unwrap_or(current_guild_voice_daily_xp_cap),
+                ),
+                voice_max_session_xp: RwLock::new(
+                    update
+                        .voice_max_session_xp
+                        .unwrap_or(current_guild_voice_max_session_xp),
+                ),
+                voice_min_members: RwLock::new(
+                    update
+                        .voice_min_members
+                        .unwrap_or(current_guild_voice_min_members),
+                ),
+                voice_xp_per_minute: RwLock::new(
+                    update
+                        .voice_xp_per_minute
+                        .unwrap_or(current_guild_voice_xp_per_minute),
+                ),
                 xp_multiplier: RwLock::new(
                     update.xp_multiplier.unwrap_or(current_guild_xp_multiplier),
                 ),
@@ -149,3 +318,31 @@ impl Cache {
         );
     }
 }
+
+impl Guild {
+    pub fn effective_xp_multiplier(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        role_ids: &HashSet<Id<RoleMarker>>,
+    ) -> f64 {
+        let mut multipliers = vec![*self.xp_multiplier.read()];
+
+        if let Some(channel_multiplier) = self.channel_multipliers.read().get(&channel_id) {
+            multipliers.push(*channel_multiplier);
+        }
+
+        multipliers.extend(
+            self.role_multipliers
+                .read()
+                .iter()
+                .filter_map(|(role_id, multiplier)| {
+                    role_ids.contains(role_id).then(|| *multiplier)
+                }),
+        );
+
+        match *self.multiplier_mode.read() {
+            MultiplierMode::Max => multipliers.into_iter().fold(f64::MIN, f64::max),
+            MultiplierMode::Product => multipliers.into_iter().product(),
+        }
+    }
+}
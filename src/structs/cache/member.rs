@@ -7,9 +7,25 @@ use twilight_model::id::{
     Id,
 };
 
-use crate::types::cache::{Cache, Member, MemberUpdate};
+use crate::types::cache::{Cache, Member, MemberUpdate, VoiceStatus};
 
 impl Cache {
+    pub fn advance_joined_voice_timestamp(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        now: OffsetDateTime,
+    ) -> Option<OffsetDateTime> {
+        let members = self.members.write();
+        let member = members.get(&(guild_id, user_id))?;
+        let mut joined_voice_timestamp = member.joined_voice_timestamp.write();
+        let previous_joined_voice_timestamp = (*joined_voice_timestamp)?;
+
+        *joined_voice_timestamp = Some(now);
+
+        Some(previous_joined_voice_timestamp)
+    }
+
     pub fn get_member(
         &self,
         guild_id: Id<GuildMarker>,
@@ -47,6 +63,8 @@ impl Cache {
                 user_id,
                 username,
                 voice_channel_id: RwLock::new(voice_channel_id),
+                voice_session_xp: RwLock::new(0),
+                voice_status: RwLock::new(VoiceStatus::default()),
                 xp: RwLock::new(xp),
             }),
         );
@@ -79,7 +97,8 @@ impl Cache {
         user_id: Id<UserMarker>,
         update: MemberUpdate,
     ) {
-        let Some(current_member) = self.get_member(guild_id, user_id) else {
+        let mut members = self.members.write();
+        let Some(current_member) = members.get(&(guild_id, user_id)).cloned() else {
             return
         };
         let current_member_avatar_url = current_member.avatar_url.read().clone();
@@ -91,9 +110,11 @@ impl Cache {
         let current_member_role_ids = current_member.role_ids.read().clone();
         let current_member_username = current_member.username.clone();
         let current_member_voice_channel_id = current_member.voice_channel_id.read().clone();
+        let current_member_voice_session_xp = *current_member.voice_session_xp.read();
+        let current_member_voice_status = *current_member.voice_status.read();
         let current_member_xp = current_member.xp.read().clone();
 
-        self.members.write().insert(
+        members.insert(
             (guild_id, user_id),
             Arc::new(Member {
                 avatar_url: RwLock::new(update.avatar_url.unwrap_or(current_member_avatar_url)),
@@ -118,6 +139,16 @@ impl Cache {
                         .voice_channel_id
                         .unwrap_or(current_member_voice_channel_id),
                 ),
+                voice_session_xp: RwLock::new(
+                    update
+                        .voice_session_xp
+                        .unwrap_or(current_member_voice_session_xp),
+                ),
+                voice_status: RwLock::new(
+                    update
+                        .voice_status
+                        .unwrap_or(current_member_voice_status),
+                ),
                 xp: RwLock::new(update.xp.unwrap_or(current_member_xp)),
             }),
         );
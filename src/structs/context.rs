@@ -7,7 +7,7 @@ use twilight_gateway::Latency;
 use twilight_http::client::Client as HttpClient;
 use twilight_model::oauth::Application;
 
-use crate::types::{cache::Cache, context::Context, database::Database};
+use crate::types::{cache::Cache, context::Context, database::Database, render::Renderer};
 
 impl Context {
     pub fn new(
@@ -24,6 +24,7 @@ impl Context {
             http: Arc::new(http),
             hyper: HyperClient::builder().build::<_, Body>(HttpsConnector::new()),
             latencies: RwLock::new(HashMap::new()),
+            renderer: Renderer::new(),
         }
     }
 
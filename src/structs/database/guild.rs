@@ -1,31 +1,183 @@
 use tokio_postgres::types::ToSql;
-use twilight_model::id::{marker::GuildMarker, Id};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
 
-use crate::types::{database::Database, Result};
+use crate::types::{
+    database::{
+        Database,
+        GuildSettings,
+        LevelCurve,
+        LevelUpMode,
+        MultiplierMode,
+        RoleStacking,
+    },
+    Result,
+};
+
+impl LevelCurve {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LevelCurve::Custom(_) => "custom",
+            LevelCurve::Erratic => "erratic",
+            LevelCurve::Exponential {
+                ..
+            } => "exponential",
+            LevelCurve::Fluctuating => "fluctuating",
+            LevelCurve::Linear {
+                ..
+            } => "linear",
+            LevelCurve::MediumFast => "medium_fast",
+        }
+    }
+
+    pub fn from_columns(
+        kind: &str,
+        base: f64,
+        growth: f64,
+        table: Vec<i64>,
+    ) -> Self {
+        match kind {
+            "custom" => LevelCurve::Custom(table),
+            "erratic" => LevelCurve::Erratic,
+            "exponential" => LevelCurve::Exponential {
+                base,
+                growth,
+            },
+            "linear" => LevelCurve::Linear {
+                step: base as i64,
+            },
+            "medium_fast" => LevelCurve::MediumFast,
+            _ => LevelCurve::Fluctuating,
+        }
+    }
+}
+
+impl LevelUpMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LevelUpMode::Channel => "channel",
+            LevelUpMode::Current => "current",
+            LevelUpMode::Direct => "direct",
+            LevelUpMode::Off => "off",
+        }
+    }
+}
+
+impl From<&str> for LevelUpMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "channel" => LevelUpMode::Channel,
+            "current" => LevelUpMode::Current,
+            "direct" => LevelUpMode::Direct,
+            _ => LevelUpMode::Off,
+        }
+    }
+}
+
+impl MultiplierMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MultiplierMode::Max => "max",
+            MultiplierMode::Product => "product",
+        }
+    }
+}
+
+impl From<&str> for MultiplierMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "max" => MultiplierMode::Max,
+            _ => MultiplierMode::Product,
+        }
+    }
+}
+
+impl RoleStacking {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoleStacking::HighestOnly => "highest_only",
+            RoleStacking::Stack => "stack",
+        }
+    }
+}
+
+impl From<&str> for RoleStacking {
+    fn from(value: &str) -> Self {
+        match value {
+            "highest_only" => RoleStacking::HighestOnly,
+            _ => RoleStacking::Stack,
+        }
+    }
+}
 
 impl Database {
     pub async fn insert_guild(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Result<f64> {
+    ) -> Result<GuildSettings> {
         let client = self.pool.get().await?;
         let statement = "
             INSERT INTO
                 public.guild (guild_id)
             VALUES
                 ($1)
-            ON CONFLICT
-            DO NOTHING
+            ON CONFLICT (guild_id)
+            DO UPDATE
+            SET
+                guild_id = EXCLUDED.guild_id
             RETURNING
+                cooldown_seconds,
+                level_curve,
+                level_curve_base,
+                level_curve_growth,
+                level_curve_table,
+                level_up_channel_id,
+                level_up_mode,
+                level_up_template,
+                max_xp,
+                min_xp,
+                multiplier_mode,
+                role_stacking,
+                voice_allow_muted,
+                voice_audience_multiplier,
+                voice_bonus_multiplier,
+                voice_daily_xp_cap,
+                voice_max_session_xp,
+                voice_min_members,
+                voice_xp_per_minute,
                 xp_multiplier;
         ";
         let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
-        let xp_multiplier = client
-            .query_one(statement, params)
-            .await
-            .map_or(1f64, |row| row.get::<_, f64>("xp_multiplier"));
+        let row = client.query_one(statement, params).await?;
 
-        Ok(xp_multiplier)
+        Ok(GuildSettings {
+            cooldown_seconds: row.get::<_, i64>("cooldown_seconds"),
+            level_curve: LevelCurve::from_columns(
+                row.get::<_, &str>("level_curve"),
+                row.get::<_, f64>("level_curve_base"),
+                row.get::<_, f64>("level_curve_growth"),
+                row.get::<_, Vec<i64>>("level_curve_table"),
+            ),
+            level_up_channel_id: row
+                .get::<_, Option<i64>>("level_up_channel_id")
+                .map(|channel_id| Id::new(channel_id as u64)),
+            level_up_mode: LevelUpMode::from(row.get::<_, &str>("level_up_mode")),
+            level_up_template: row.get::<_, String>("level_up_template"),
+            max_xp: row.get::<_, i64>("max_xp"),
+            min_xp: row.get::<_, i64>("min_xp"),
+            multiplier_mode: MultiplierMode::from(row.get::<_, &str>("multiplier_mode")),
+            role_stacking: RoleStacking::from(row.get::<_, &str>("role_stacking")),
+            voice_allow_muted: row.get::<_, bool>("voice_allow_muted"),
+            voice_audience_multiplier: row.get::<_, f64>("voice_audience_multiplier"),
+            voice_bonus_multiplier: row.get::<_, f64>("voice_bonus_multiplier"),
+            voice_daily_xp_cap: row.get::<_, i64>("voice_daily_xp_cap"),
+            voice_max_session_xp: row.get::<_, i64>("voice_max_session_xp"),
+            voice_min_members: row.get::<_, i64>("voice_min_members"),
+            voice_xp_per_minute: row.get::<_, i64>("voice_xp_per_minute"),
+            xp_multiplier: row.get::<_, f64>("xp_multiplier"),
+        })
     }
 
     pub async fn remove_guild(
@@ -46,6 +198,222 @@ impl Database {
         Ok(())
     }
 
+    pub async fn update_cooldown(
+        &self,
+        guild_id: Id<GuildMarker>,
+        cooldown_seconds: i64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                cooldown_seconds = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &cooldown_seconds];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_level_curve(
+        &self,
+        guild_id: Id<GuildMarker>,
+        level_curve: &LevelCurve,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                level_curve = $2,
+                level_curve_base = $3,
+                level_curve_growth = $4,
+                level_curve_table = $5
+            WHERE
+                guild_id = $1;
+        ";
+        let (base, growth, table) = match level_curve {
+            LevelCurve::Custom(table) => (100f64, 1.1f64, table.clone()),
+            LevelCurve::Exponential {
+                base,
+                growth,
+            } => (*base, *growth, Vec::new()),
+            LevelCurve::Linear {
+                step,
+            } => (*step as f64, 1.1f64, Vec::new()),
+            _ => (100f64, 1.1f64, Vec::new()),
+        };
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &level_curve.as_str(),
+            &base,
+            &growth,
+            &table,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_level_up(
+        &self,
+        guild_id: Id<GuildMarker>,
+        level_up_mode: LevelUpMode,
+        level_up_channel_id: Option<Id<ChannelMarker>>,
+        level_up_template: String,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                level_up_mode = $2,
+                level_up_channel_id = $3,
+                level_up_template = $4
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &level_up_mode.as_str(),
+            &level_up_channel_id.map(|channel_id| channel_id.get() as i64),
+            &level_up_template,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_multiplier_mode(
+        &self,
+        guild_id: Id<GuildMarker>,
+        multiplier_mode: MultiplierMode,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                multiplier_mode = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &multiplier_mode.as_str()];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_role_stacking(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_stacking: RoleStacking,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                role_stacking = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &role_stacking.as_str()];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_voice_bonus(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_bonus_multiplier: f64,
+        voice_audience_multiplier: f64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                voice_bonus_multiplier = $2,
+                voice_audience_multiplier = $3
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &voice_bonus_multiplier,
+            &voice_audience_multiplier,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_voice_rules(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_min_members: i64,
+        voice_allow_muted: bool,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                voice_min_members = $2,
+                voice_allow_muted = $3
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &voice_min_members, &voice_allow_muted];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_voice_xp(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_xp_per_minute: i64,
+        voice_max_session_xp: i64,
+        voice_daily_xp_cap: i64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                voice_xp_per_minute = $2,
+                voice_max_session_xp = $3,
+                voice_daily_xp_cap = $4
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &voice_xp_per_minute,
+            &voice_max_session_xp,
+            &voice_daily_xp_cap,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn update_xp_multiplier(
         &self,
         guild_id: Id<GuildMarker>,
@@ -66,4 +434,27 @@ impl Database {
 
         Ok(())
     }
+
+    pub async fn update_xp_range(
+        &self,
+        guild_id: Id<GuildMarker>,
+        min_xp: i64,
+        max_xp: i64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                min_xp = $2,
+                max_xp = $3
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &min_xp, &max_xp];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,102 @@
+use time::Date;
+use tokio_postgres::types::ToSql;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::types::{
+    database::{Database, XpSource},
+    Result,
+};
+
+impl Database {
+    pub async fn get_xp_history_leaderboard(
+        &self,
+        guild_id: Id<GuildMarker>,
+        since: Date,
+    ) -> Result<Vec<(Id<UserMarker>, i64)>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                user_id,
+                SUM(xp)::INT8 AS xp
+            FROM
+                public.xp_history
+            WHERE
+                guild_id = $1
+                AND day >= $2
+            GROUP BY
+                user_id
+            HAVING
+                SUM(xp) > 0
+            ORDER BY
+                xp DESC,
+                user_id;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &since];
+        let leaderboard = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::<UserMarker>::new(row.get::<_, i64>("user_id") as u64),
+                    row.get::<_, i64>("xp"),
+                )
+            })
+            .collect();
+
+        Ok(leaderboard)
+    }
+
+    pub async fn get_member_xp_sources(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<(i64, i64)> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                COALESCE(SUM(xp - voice_xp), 0)::INT8 AS message_xp,
+                COALESCE(SUM(voice_xp), 0)::INT8 AS voice_xp
+            FROM
+                public.xp_history
+            WHERE
+                guild_id = $1
+                AND user_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &(user_id.get() as i64)];
+        let row = client.query_one(statement, params).await?;
+
+        Ok((row.get("message_xp"), row.get("voice_xp")))
+    }
+
+    pub async fn insert_xp_history(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        xp: i64,
+        source: XpSource,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.xp_history (guild_id, user_id, day, xp, voice_xp)
+            VALUES
+                ($1, $2, (NOW() AT TIME ZONE 'UTC')::DATE, $3, $4)
+            ON CONFLICT (guild_id, user_id, day)
+            DO UPDATE
+            SET
+                xp = public.xp_history.xp + EXCLUDED.xp,
+                voice_xp = public.xp_history.voice_xp + EXCLUDED.voice_xp;
+        ";
+        let voice_xp = if source == XpSource::Voice { xp } else { 0 };
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(user_id.get() as i64), &xp, &voice_xp];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use tokio_postgres::types::ToSql;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, RoleMarker},
+    Id,
+};
+
+use crate::types::{
+    database::{Database, IgnoredKind},
+    Result,
+};
+
+impl IgnoredKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IgnoredKind::Role => "role",
+            IgnoredKind::TextChannel => "text_channel",
+            IgnoredKind::VoiceChannel => "voice_channel",
+        }
+    }
+}
+
+impl Database {
+    pub async fn get_ignored(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<(
+        HashSet<Id<RoleMarker>>,
+        HashSet<Id<ChannelMarker>>,
+        HashSet<Id<ChannelMarker>>,
+    )> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                kind,
+                id
+            FROM
+                public.ignored
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let mut role_ids: HashSet<Id<RoleMarker>> = HashSet::new();
+        let mut text_channel_ids: HashSet<Id<ChannelMarker>> = HashSet::new();
+        let mut voice_channel_ids: HashSet<Id<ChannelMarker>> = HashSet::new();
+
+        for row in client.query(statement, params).await? {
+            let id = row.get::<_, i64>("id") as u64;
+
+            match row.get::<_, &str>("kind") {
+                "role" => {
+                    role_ids.insert(Id::new(id));
+                }
+                "text_channel" => {
+                    text_channel_ids.insert(Id::new(id));
+                }
+                "voice_channel" => {
+                    voice_channel_ids.insert(Id::new(id));
+                }
+                _ => {}
+            }
+        }
+
+        Ok((role_ids, text_channel_ids, voice_channel_ids))
+    }
+
+    pub async fn insert_ignored(
+        &self,
+        guild_id: Id<GuildMarker>,
+        kind: IgnoredKind,
+        id: u64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.ignored
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT (guild_id, kind, id)
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &kind.as_str(), &(id as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_guild_ignored(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.ignored
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_ignored(
+        &self,
+        guild_id: Id<GuildMarker>,
+        kind: IgnoredKind,
+        id: u64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.ignored
+            WHERE
+                guild_id = $1
+                AND kind = $2
+                AND id = $3;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &kind.as_str(), &(id as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+}
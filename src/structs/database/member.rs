@@ -8,6 +8,35 @@ use twilight_model::id::{
 use crate::types::{database::Database, Result};
 
 impl Database {
+    pub async fn add_member_daily_voice_xp(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        xp: i64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.member
+            SET
+                daily_voice_xp = CASE
+                    WHEN daily_voice_xp_date = (NOW() AT TIME ZONE 'UTC')::DATE
+                    THEN daily_voice_xp + $3
+                    ELSE $3
+                END,
+                daily_voice_xp_date = (NOW() AT TIME ZONE 'UTC')::DATE
+            WHERE
+                guild_id = $1
+                AND user_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(user_id.get() as i64), &xp];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn get_member(
         &self,
         guild_id: Id<GuildMarker>,
@@ -38,6 +67,31 @@ impl Database {
         Ok(member)
     }
 
+    pub async fn get_member_daily_voice_xp(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<i64> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                daily_voice_xp
+            FROM
+                public.member
+            WHERE
+                guild_id = $1
+                AND user_id = $2
+                AND daily_voice_xp_date = (NOW() AT TIME ZONE 'UTC')::DATE;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &(user_id.get() as i64)];
+        let daily_voice_xp = client
+            .query_opt(statement, params)
+            .await?
+            .map_or(0, |row| row.get::<_, i64>("daily_voice_xp"));
+
+        Ok(daily_voice_xp)
+    }
+
     pub async fn get_members(
         &self,
         guild_id: Id<GuildMarker>,
@@ -70,6 +124,94 @@ impl Database {
         Ok(members)
     }
 
+    pub async fn increment_member_xp(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        xp: i64,
+        last_message_timestamp: Option<OffsetDateTime>,
+    ) -> Result<i64> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.member (guild_id, user_id, xp, last_message_timestamp)
+            VALUES
+                ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, user_id)
+            DO UPDATE
+            SET
+                xp = public.member.xp + $3,
+                last_message_timestamp = COALESCE($4, public.member.last_message_timestamp)
+            RETURNING
+                xp;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &(user_id.get() as i64),
+            &xp,
+            &last_message_timestamp,
+        ];
+        let updated_xp = client
+            .query_one(statement, params)
+            .await?
+            .get::<_, i64>("xp");
+
+        Ok(updated_xp)
+    }
+
+    pub async fn insert_members_xp(
+        &self,
+        guild_id: Id<GuildMarker>,
+        members: &[(Id<UserMarker>, i64)],
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.member (guild_id, user_id, xp)
+            SELECT
+                $1,
+                *
+            FROM
+                UNNEST($2::INT8[], $3::INT8[])
+            ON CONFLICT (guild_id, user_id)
+            DO UPDATE
+            SET
+                xp = EXCLUDED.xp;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &members
+                .iter()
+                .map(|(user_id, _)| user_id.get() as i64)
+                .collect::<Vec<i64>>(),
+            &members.iter().map(|(_, xp)| *xp).collect::<Vec<i64>>(),
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn reset_guild_xp(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.member
+            SET
+                xp = 0
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn update_member_xp(
         &self,
         guild_id: Id<GuildMarker>,
@@ -87,7 +229,7 @@ impl Database {
             DO UPDATE
             SET
                 xp = $3,
-                last_message_timestamp = COALESCE($4, EXCLUDED.last_message_timestamp);
+                last_message_timestamp = COALESCE($4, public.member.last_message_timestamp);
         ";
         let params: &[&(dyn ToSql + Sync)] = &[
             &(guild_id.get() as i64),
@@ -1,6 +1,11 @@
 mod guild;
+mod history;
+mod ignored;
 mod level;
 mod member;
+mod multiplier;
+mod rank_card;
+mod season;
 
 use std::str::FromStr;
 
@@ -19,7 +24,63 @@ impl Database {
             -- guild table
             CREATE TABLE IF NOT EXISTS public.guild (
                 guild_id INT8 NOT NULL PRIMARY KEY,
-                xp_multiplier FLOAT8 NOT NULL DEFAULT 1
+                xp_multiplier FLOAT8 NOT NULL DEFAULT 1,
+                min_xp INT8 NOT NULL DEFAULT 35,
+                max_xp INT8 NOT NULL DEFAULT 45,
+                cooldown_seconds INT8 NOT NULL DEFAULT 60,
+                multiplier_mode TEXT NOT NULL DEFAULT 'product',
+                level_up_mode TEXT NOT NULL DEFAULT 'off',
+                level_up_channel_id INT8,
+                level_up_template TEXT NOT NULL DEFAULT '{user} has reached level {level}!',
+                level_curve TEXT NOT NULL DEFAULT 'fluctuating',
+                level_curve_base FLOAT8 NOT NULL DEFAULT 100,
+                level_curve_growth FLOAT8 NOT NULL DEFAULT 1.1,
+                level_curve_table INT8[] NOT NULL DEFAULT '{}'::INT8[],
+                role_stacking TEXT NOT NULL DEFAULT 'stack',
+                voice_allow_muted BOOL NOT NULL DEFAULT false,
+                voice_min_members INT8 NOT NULL DEFAULT 2,
+                voice_daily_xp_cap INT8 NOT NULL DEFAULT 0,
+                voice_max_session_xp INT8 NOT NULL DEFAULT 0,
+                voice_xp_per_minute INT8 NOT NULL DEFAULT 15,
+                voice_audience_multiplier FLOAT8 NOT NULL DEFAULT 0.5,
+                voice_bonus_multiplier FLOAT8 NOT NULL DEFAULT 1.5
+            );
+
+            ALTER TABLE public.guild
+                ADD COLUMN IF NOT EXISTS min_xp INT8 NOT NULL DEFAULT 35,
+                ADD COLUMN IF NOT EXISTS max_xp INT8 NOT NULL DEFAULT 45,
+                ADD COLUMN IF NOT EXISTS cooldown_seconds INT8 NOT NULL DEFAULT 60,
+                ADD COLUMN IF NOT EXISTS multiplier_mode TEXT NOT NULL DEFAULT 'product',
+                ADD COLUMN IF NOT EXISTS level_up_mode TEXT NOT NULL DEFAULT 'off',
+                ADD COLUMN IF NOT EXISTS level_up_channel_id INT8,
+                ADD COLUMN IF NOT EXISTS level_up_template TEXT NOT NULL DEFAULT '{user} has reached level {level}!',
+                ADD COLUMN IF NOT EXISTS level_curve TEXT NOT NULL DEFAULT 'fluctuating',
+                ADD COLUMN IF NOT EXISTS level_curve_base FLOAT8 NOT NULL DEFAULT 100,
+                ADD COLUMN IF NOT EXISTS level_curve_growth FLOAT8 NOT NULL DEFAULT 1.1,
+                ADD COLUMN IF NOT EXISTS level_curve_table INT8[] NOT NULL DEFAULT '{}'::INT8[],
+                ADD COLUMN IF NOT EXISTS role_stacking TEXT NOT NULL DEFAULT 'stack',
+                ADD COLUMN IF NOT EXISTS voice_allow_muted BOOL NOT NULL DEFAULT false,
+                ADD COLUMN IF NOT EXISTS voice_min_members INT8 NOT NULL DEFAULT 2,
+                ADD COLUMN IF NOT EXISTS voice_daily_xp_cap INT8 NOT NULL DEFAULT 0,
+                ADD COLUMN IF NOT EXISTS voice_max_session_xp INT8 NOT NULL DEFAULT 0,
+                ADD COLUMN IF NOT EXISTS voice_xp_per_minute INT8 NOT NULL DEFAULT 15,
+                ADD COLUMN IF NOT EXISTS voice_audience_multiplier FLOAT8 NOT NULL DEFAULT 0.5,
+                ADD COLUMN IF NOT EXISTS voice_bonus_multiplier FLOAT8 NOT NULL DEFAULT 1.5;
+
+            -- channel multiplier table
+            CREATE TABLE IF NOT EXISTS public.channel_multiplier (
+                guild_id INT8 NOT NULL,
+                channel_id INT8 NOT NULL,
+                multiplier FLOAT8 NOT NULL,
+                PRIMARY KEY (guild_id, channel_id)
+            );
+
+            -- ignored table
+            CREATE TABLE IF NOT EXISTS public.ignored (
+                guild_id INT8 NOT NULL,
+                kind TEXT NOT NULL,
+                id INT8 NOT NULL,
+                PRIMARY KEY (guild_id, kind, id)
             );
 
             -- level table
@@ -37,8 +98,80 @@ impl Database {
                 xp INT8 NOT NULL DEFAULT 0,
                 last_message_timestamp TIMESTAMP WITH TIME ZONE,
                 updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                daily_voice_xp INT8 NOT NULL DEFAULT 0,
+                daily_voice_xp_date DATE,
+                PRIMARY KEY (guild_id, user_id)
+            );
+
+            ALTER TABLE public.member
+                ADD COLUMN IF NOT EXISTS daily_voice_xp INT8 NOT NULL DEFAULT 0,
+                ADD COLUMN IF NOT EXISTS daily_voice_xp_date DATE;
+
+            -- rank card theme table, where a user_id of 0 holds the guild default
+            CREATE TABLE IF NOT EXISTS public.rank_card_theme (
+                guild_id INT8 NOT NULL,
+                user_id INT8 NOT NULL DEFAULT 0,
+                accent_color INT8,
+                background BYTEA,
+                bar_style TEXT,
+                font TEXT,
+                overlay_opacity INT8,
                 PRIMARY KEY (guild_id, user_id)
-            )
+            );
+
+            -- role multiplier table
+            CREATE TABLE IF NOT EXISTS public.role_multiplier (
+                guild_id INT8 NOT NULL,
+                role_id INT8 NOT NULL,
+                multiplier FLOAT8 NOT NULL,
+                PRIMARY KEY (guild_id, role_id)
+            );
+
+            -- season table
+            CREATE TABLE IF NOT EXISTS public.season (
+                guild_id INT8 NOT NULL,
+                season_number INT8 NOT NULL,
+                started_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                ends_at TIMESTAMP WITH TIME ZONE,
+                ended_at TIMESTAMP WITH TIME ZONE,
+                archive_size INT8 NOT NULL DEFAULT 10,
+                PRIMARY KEY (guild_id, season_number)
+            );
+
+            ALTER TABLE public.season
+                ADD COLUMN IF NOT EXISTS duration_days INT8;
+
+            -- season member table
+            CREATE TABLE IF NOT EXISTS public.season_member (
+                guild_id INT8 NOT NULL,
+                season_number INT8 NOT NULL,
+                user_id INT8 NOT NULL,
+                xp INT8 NOT NULL DEFAULT 0,
+                PRIMARY KEY (guild_id, season_number, user_id)
+            );
+
+            -- season archive table
+            CREATE TABLE IF NOT EXISTS public.season_archive (
+                guild_id INT8 NOT NULL,
+                season_number INT8 NOT NULL,
+                rank INT8 NOT NULL,
+                user_id INT8 NOT NULL,
+                xp INT8 NOT NULL,
+                PRIMARY KEY (guild_id, season_number, rank)
+            );
+
+            -- xp history table
+            CREATE TABLE IF NOT EXISTS public.xp_history (
+                guild_id INT8 NOT NULL,
+                user_id INT8 NOT NULL,
+                day DATE NOT NULL,
+                xp INT8 NOT NULL DEFAULT 0,
+                voice_xp INT8 NOT NULL DEFAULT 0,
+                PRIMARY KEY (guild_id, user_id, day)
+            );
+
+            ALTER TABLE public.xp_history
+                ADD COLUMN IF NOT EXISTS voice_xp INT8 NOT NULL DEFAULT 0
         ";
 
         client.batch_execute(statement).await?;
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use tokio_postgres::types::ToSql;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, RoleMarker},
+    Id,
+};
+
+use crate::types::{database::Database, Result};
+
+impl Database {
+    pub async fn get_channel_multipliers(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<HashMap<Id<ChannelMarker>, f64>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                channel_id,
+                multiplier
+            FROM
+                public.channel_multiplier
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let channel_multipliers = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::new(row.get::<_, i64>("channel_id") as u64),
+                    row.get::<_, f64>("multiplier"),
+                )
+            })
+            .collect::<HashMap<Id<ChannelMarker>, f64>>();
+
+        Ok(channel_multipliers)
+    }
+
+    pub async fn get_role_multipliers(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<HashMap<Id<RoleMarker>, f64>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                role_id,
+                multiplier
+            FROM
+                public.role_multiplier
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let role_multipliers = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::new(row.get::<_, i64>("role_id") as u64),
+                    row.get::<_, f64>("multiplier"),
+                )
+            })
+            .collect::<HashMap<Id<RoleMarker>, f64>>();
+
+        Ok(role_multipliers)
+    }
+
+    pub async fn insert_channel_multiplier(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        multiplier: f64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.channel_multiplier
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT (guild_id, channel_id)
+            DO UPDATE
+            SET
+                multiplier = $3;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &(channel_id.get() as i64),
+            &multiplier,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_role_multiplier(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+        multiplier: f64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.role_multiplier
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT (guild_id, role_id)
+            DO UPDATE
+            SET
+                multiplier = $3;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &(role_id.get() as i64),
+            &multiplier,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_channel_multiplier(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.channel_multiplier
+            WHERE
+                guild_id = $1
+                AND channel_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(channel_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_guild_multipliers(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let channel_statement = "
+            DELETE FROM
+                public.channel_multiplier
+            WHERE
+                guild_id = $1;
+        ";
+        let role_statement = "
+            DELETE FROM
+                public.role_multiplier
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client.execute(channel_statement, params).await?;
+        client.execute(role_statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_role_multiplier(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.role_multiplier
+            WHERE
+                guild_id = $1
+                AND role_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &(role_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+}
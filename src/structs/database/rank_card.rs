@@ -0,0 +1,221 @@
+use tokio_postgres::types::ToSql;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::types::{
+    database::{Database, RankCardBarStyle, RankCardFont, RankCardTheme, RankCardThemeUpdate},
+    Result,
+};
+
+impl RankCardBarStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RankCardBarStyle::Rounded => "rounded",
+            RankCardBarStyle::Square => "square",
+            RankCardBarStyle::Thin => "thin",
+        }
+    }
+}
+
+impl From<&str> for RankCardBarStyle {
+    fn from(value: &str) -> Self {
+        match value {
+            "square" => RankCardBarStyle::Square,
+            "thin" => RankCardBarStyle::Thin,
+            _ => RankCardBarStyle::Rounded,
+        }
+    }
+}
+
+impl RankCardFont {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RankCardFont::Monospace => "monospace",
+            RankCardFont::Serif => "serif",
+            RankCardFont::SourceSans3 => "source_sans_3",
+        }
+    }
+}
+
+impl From<&str> for RankCardFont {
+    fn from(value: &str) -> Self {
+        match value {
+            "monospace" => RankCardFont::Monospace,
+            "serif" => RankCardFont::Serif,
+            _ => RankCardFont::SourceSans3,
+        }
+    }
+}
+
+impl Default for RankCardTheme {
+    fn default() -> Self {
+        Self {
+            accent_color: 0xC9AD7F,
+            background: None,
+            bar_style: RankCardBarStyle::default(),
+            font: RankCardFont::default(),
+            overlay_opacity: 50,
+        }
+    }
+}
+
+impl RankCardTheme {
+    pub fn apply(&mut self, update: RankCardThemeUpdate) {
+        if let Some(accent_color) = update.accent_color {
+            self.accent_color = accent_color;
+        }
+
+        if let Some(background) = update.background {
+            self.background = Some(background);
+        }
+
+        if let Some(bar_style) = update.bar_style {
+            self.bar_style = bar_style;
+        }
+
+        if let Some(font) = update.font {
+            self.font = font;
+        }
+
+        if let Some(overlay_opacity) = update.overlay_opacity {
+            self.overlay_opacity = overlay_opacity;
+        }
+    }
+}
+
+impl Database {
+    pub async fn delete_rank_card_background(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.rank_card_theme
+            SET
+                background = NULL
+            WHERE
+                guild_id = $1
+                AND user_id = 0;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_rank_card_theme(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.rank_card_theme
+            WHERE
+                guild_id = $1
+                AND user_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &user_id.map_or(0, |user_id| user_id.get() as i64),
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_rank_card_theme(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Option<Id<UserMarker>>,
+    ) -> Result<RankCardTheme> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                accent_color,
+                background,
+                bar_style,
+                font,
+                overlay_opacity
+            FROM
+                public.rank_card_theme
+            WHERE
+                guild_id = $1
+                AND user_id IN (0, $2)
+            ORDER BY
+                user_id;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &user_id.map_or(0, |user_id| user_id.get() as i64),
+        ];
+        let mut theme = RankCardTheme::default();
+
+        for row in client.query(statement, params).await? {
+            theme.apply(RankCardThemeUpdate {
+                accent_color: row
+                    .get::<_, Option<i64>>("accent_color")
+                    .map(|accent_color| accent_color as u32),
+                background: row.get("background"),
+                bar_style: row.get::<_, Option<&str>>("bar_style").map(RankCardBarStyle::from),
+                font: row.get::<_, Option<&str>>("font").map(RankCardFont::from),
+                overlay_opacity: row.get("overlay_opacity"),
+            });
+        }
+
+        Ok(theme)
+    }
+
+    pub async fn update_rank_card_theme(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Option<Id<UserMarker>>,
+        update: RankCardThemeUpdate,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.rank_card_theme (
+                    guild_id,
+                    user_id,
+                    accent_color,
+                    background,
+                    bar_style,
+                    font,
+                    overlay_opacity
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (guild_id, user_id)
+            DO UPDATE
+            SET
+                accent_color = COALESCE(
+                    EXCLUDED.accent_color,
+                    public.rank_card_theme.accent_color
+                ),
+                background = COALESCE(EXCLUDED.background, public.rank_card_theme.background),
+                bar_style = COALESCE(EXCLUDED.bar_style, public.rank_card_theme.bar_style),
+                font = COALESCE(EXCLUDED.font, public.rank_card_theme.font),
+                overlay_opacity = COALESCE(
+                    EXCLUDED.overlay_opacity,
+                    public.rank_card_theme.overlay_opacity
+                );
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &user_id.map_or(0, |user_id| user_id.get() as i64),
+            &update.accent_color.map(|accent_color| accent_color as i64),
+            &update.background,
+            &update.bar_style.map(|bar_style| bar_style.as_str()),
+            &update.font.map(|font| font.as_str()),
+            &update.overlay_opacity,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,306 @@
+use time::OffsetDateTime;
+use tokio_postgres::{types::ToSql, Row};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::types::{
+    database::{Database, Season},
+    Result,
+};
+
+impl Database {
+    pub async fn end_season(
+        &self,
+        guild_id: Id<GuildMarker>,
+        season_number: i64,
+        start_next: bool,
+    ) -> Result<bool> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let statement = "
+            UPDATE
+                public.season
+            SET
+                ended_at = NOW()
+            WHERE
+                guild_id = $1
+                AND season_number = $2
+                AND ended_at IS NULL
+            RETURNING
+                archive_size;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &season_number];
+        let Some(row) = transaction.query_opt(statement, params).await? else {
+            return Ok(false);
+        };
+        let archive_size = row.get::<_, i64>("archive_size");
+        let statement = "
+            INSERT INTO
+                public.season_archive (guild_id, season_number, rank, user_id, xp)
+            SELECT
+                guild_id,
+                season_number,
+                ROW_NUMBER() OVER (ORDER BY xp DESC, user_id),
+                user_id,
+                xp
+            FROM
+                public.season_member
+            WHERE
+                guild_id = $1
+                AND season_number = $2
+                AND xp > 0
+            ORDER BY
+                xp DESC,
+                user_id
+            LIMIT
+                $3
+            ON CONFLICT (guild_id, season_number, rank)
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &season_number, &archive_size];
+
+        transaction.execute(statement, params).await?;
+
+        if start_next {
+            let statement = "
+                INSERT INTO
+                    public.season (guild_id, season_number, ends_at, archive_size, duration_days)
+                SELECT
+                    guild_id,
+                    season_number + 1,
+                    NOW() + MAKE_INTERVAL(days => duration_days::INT4),
+                    archive_size,
+                    duration_days
+                FROM
+                    public.season
+                WHERE
+                    guild_id = $1
+                    AND season_number = $2
+                    AND duration_days IS NOT NULL;
+            ";
+            let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &season_number];
+
+            transaction.execute(statement, params).await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(true)
+    }
+
+    pub async fn get_active_season(&self, guild_id: Id<GuildMarker>) -> Result<Option<Season>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                archive_size,
+                ended_at,
+                ends_at,
+                season_number,
+                started_at
+            FROM
+                public.season
+            WHERE
+                guild_id = $1
+                AND ended_at IS NULL;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let season = client
+            .query_opt(statement, params)
+            .await?
+            .map(|row| season_from_row(&row));
+
+        Ok(season)
+    }
+
+    pub async fn get_expired_seasons(&self) -> Result<Vec<(Id<GuildMarker>, i64)>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                guild_id,
+                season_number
+            FROM
+                public.season
+            WHERE
+                ended_at IS NULL
+                AND ends_at <= NOW();
+        ";
+        let seasons = client
+            .query(statement, &[])
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::<GuildMarker>::new(row.get::<_, i64>("guild_id") as u64),
+                    row.get::<_, i64>("season_number"),
+                )
+            })
+            .collect();
+
+        Ok(seasons)
+    }
+
+    pub async fn get_season(
+        &self,
+        guild_id: Id<GuildMarker>,
+        season_number: i64,
+    ) -> Result<Option<Season>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                archive_size,
+                ended_at,
+                ends_at,
+                season_number,
+                started_at
+            FROM
+                public.season
+            WHERE
+                guild_id = $1
+                AND season_number = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &season_number];
+        let season = client
+            .query_opt(statement, params)
+            .await?
+            .map(|row| season_from_row(&row));
+
+        Ok(season)
+    }
+
+    pub async fn get_season_leaderboard(
+        &self,
+        guild_id: Id<GuildMarker>,
+        season: &Season,
+    ) -> Result<Vec<(Id<UserMarker>, i64)>> {
+        let client = self.pool.get().await?;
+        let statement = if season.ended_at.is_some() {
+            "
+            SELECT
+                user_id,
+                xp
+            FROM
+                public.season_archive
+            WHERE
+                guild_id = $1
+                AND season_number = $2
+            ORDER BY
+                rank;
+            "
+        } else {
+            "
+            SELECT
+                user_id,
+                xp
+            FROM
+                public.season_member
+            WHERE
+                guild_id = $1
+                AND season_number = $2
+                AND xp > 0
+            ORDER BY
+                xp DESC,
+                user_id;
+            "
+        };
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &season.season_number];
+        let leaderboard = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::<UserMarker>::new(row.get::<_, i64>("user_id") as u64),
+                    row.get::<_, i64>("xp"),
+                )
+            })
+            .collect();
+
+        Ok(leaderboard)
+    }
+
+    pub async fn insert_season(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ends_at: Option<OffsetDateTime>,
+        archive_size: i64,
+        duration_days: Option<i64>,
+    ) -> Result<Option<i64>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.season (guild_id, season_number, ends_at, archive_size, duration_days)
+            SELECT
+                $1,
+                COALESCE(MAX(season_number), 0) + 1,
+                $2,
+                $3,
+                $4
+            FROM
+                public.season
+            WHERE
+                guild_id = $1
+            HAVING
+                COUNT(*) FILTER (WHERE ended_at IS NULL) = 0
+            RETURNING
+                season_number;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &ends_at,
+            &archive_size,
+            &duration_days,
+        ];
+        let season_number = client
+            .query_opt(statement, params)
+            .await?
+            .map(|row| row.get::<_, i64>("season_number"));
+
+        Ok(season_number)
+    }
+
+    pub async fn insert_season_xp(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        xp: i64,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.season_member (guild_id, season_number, user_id, xp)
+            SELECT
+                guild_id,
+                season_number,
+                $2,
+                $3
+            FROM
+                public.season
+            WHERE
+                guild_id = $1
+                AND ended_at IS NULL
+            ON CONFLICT (guild_id, season_number, user_id)
+            DO UPDATE
+            SET
+                xp = public.season_member.xp + EXCLUDED.xp;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(user_id.get() as i64), &xp];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+}
+
+fn season_from_row(row: &Row) -> Season {
+    Season {
+        archive_size: row.get("archive_size"),
+        ended_at: row.get("ended_at"),
+        ends_at: row.get("ends_at"),
+        season_number: row.get("season_number"),
+        started_at: row.get("started_at"),
+    }
+}
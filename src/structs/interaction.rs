@@ -1,20 +1,30 @@
-use std::{borrow::Cow, mem::take};
+use std::{borrow::Cow, mem::take, time::Instant};
 
 use twilight_interactions::command::CommandInputData;
 use twilight_model::{
-    channel::{message::MessageFlags, Message},
+    channel::{
+        message::{Embed, MessageFlags},
+        Message,
+    },
+    guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+    id::{marker::MessageMarker, Id},
 };
-
-use crate::types::{
-    interaction::{
-        ApplicationCommandInteraction,
-        DeferInteractionPayload,
-        InteractionContext,
-        ResponsePayload,
-        UpdatePayload,
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            InteractionContext,
+            ResponsePayload,
+            UpdatePayload,
+        },
+        Result,
     },
-    Result,
+    utility::constants::INTERACTION_RESPONSE_DEADLINE_SECONDS,
 };
 
 impl ApplicationCommandInteraction<'_> {
@@ -24,9 +34,99 @@ impl ApplicationCommandInteraction<'_> {
             resolved: self.data.resolved.take().map(Cow::Owned),
         }
     }
+
+    pub async fn require_administrator(
+        &self,
+        action: &str,
+    ) -> Result<bool> {
+        if self.user_permissions.map_or(false, |permissions| {
+            permissions.contains(Permissions::ADMINISTRATOR)
+        }) {
+            return Ok(true);
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "You must have administrator permissions in order to {action}."
+            ))
+            .build();
+
+        self.context
+            .respond(ResponsePayload {
+                embeds: vec![embed],
+                ephemeral: true,
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(false)
+    }
+
+    pub async fn update_progress(
+        &self,
+        context: &Context,
+        progress_message_id: &mut Option<Id<MessageMarker>>,
+        started_at: Instant,
+        embed: Embed,
+    ) -> Result<()> {
+        if started_at.elapsed().as_secs().lt(&INTERACTION_RESPONSE_DEADLINE_SECONDS) {
+            return self
+                .context
+                .update_response(UpdatePayload {
+                    embeds: vec![embed],
+                    ..Default::default()
+                })
+                .await;
+        }
+
+        let Some(channel_id) = self.channel_id else {
+            return Ok(())
+        };
+        let embeds = [embed];
+
+        match progress_message_id {
+            Some(message_id) => {
+                context
+                    .http
+                    .update_message(channel_id, *message_id)
+                    .embeds(Some(&embeds))?
+                    .await?;
+            }
+            None => {
+                let message = context
+                    .http
+                    .create_message(channel_id)
+                    .embeds(&embeds)?
+                    .await?
+                    .model()
+                    .await?;
+
+                *progress_message_id = Some(message.id);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl InteractionContext<'_> {
+    pub async fn clear_components(&self) -> Result<()> {
+        let response = InteractionResponse {
+            data: Some(InteractionResponseData {
+                components: Some(Vec::new()),
+                ..Default::default()
+            }),
+            kind: InteractionResponseType::UpdateMessage,
+        };
+
+        self.interaction_client
+            .create_response(self.id, &self.token, &response)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn defer(
         &self,
         payload: DeferInteractionPayload,
@@ -2,3 +2,4 @@ pub mod cache;
 pub mod context;
 pub mod database;
 pub mod interaction;
+pub mod render;
@@ -0,0 +1,217 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use hyper::client::{Client as HyperClient, HttpConnector};
+use hyper_tls::HttpsConnector;
+use parking_lot::Mutex;
+use skia_safe::{Data, Image};
+use tokio::{sync::Semaphore, task::spawn_blocking};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    types::{
+        database::RankCardTheme,
+        render::{ImageCache, Renderer},
+        Result,
+    },
+    utility::{
+        constants::{
+            RENDER_ANIMATED_AVATAR_CACHE_SIZE,
+            RENDER_AVATAR_CACHE_SIZE,
+            RENDER_BACKGROUND_CACHE_SIZE,
+            RENDER_CONCURRENCY,
+        },
+        image::{decode_gif_frames, get_background_image},
+    },
+};
+
+impl<T: Clone> ImageCache<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    pub fn get(
+        &mut self,
+        key: &str,
+    ) -> Option<T> {
+        self.tick += 1;
+
+        let tick = self.tick;
+
+        self.entries.get_mut(key).map(|(image, last_used)| {
+            *last_used = tick;
+
+            image.clone()
+        })
+    }
+
+    pub fn insert(
+        &mut self,
+        key: String,
+        image: T,
+    ) {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            let least_recently_used = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+
+            if let Some(least_recently_used) = least_recently_used {
+                self.entries.remove(&least_recently_used);
+            }
+        }
+
+        self.tick += 1;
+        self.entries.insert(key, (image, self.tick));
+    }
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Self {
+            animated_avatars: Mutex::new(ImageCache::new(RENDER_ANIMATED_AVATAR_CACHE_SIZE)),
+            avatars: Mutex::new(ImageCache::new(RENDER_AVATAR_CACHE_SIZE)),
+            backgrounds: Mutex::new(ImageCache::new(RENDER_BACKGROUND_CACHE_SIZE)),
+            permits: Semaphore::new(RENDER_CONCURRENCY),
+        }
+    }
+
+    pub async fn avatar(
+        &self,
+        hyper: &HyperClient<HttpsConnector<HttpConnector>>,
+        avatar_url: &str,
+        fallback_url: &str,
+    ) -> Option<Image> {
+        for url in [avatar_url, fallback_url] {
+            if let Some(image) = self.avatars.lock().get(url) {
+                return Some(image);
+            }
+
+            let Some(bytes) = fetch_image_bytes(hyper, url).await else {
+                continue;
+            };
+
+            if let Ok(Some(image)) = self.render(move || decode_image(&bytes)).await {
+                self.avatars.lock().insert(url.to_owned(), image.clone());
+
+                return Some(image);
+            }
+        }
+
+        None
+    }
+
+    pub async fn animated_avatar(
+        &self,
+        hyper: &HyperClient<HttpsConnector<HttpConnector>>,
+        avatar_url: &str,
+    ) -> Option<Vec<(Image, u32)>> {
+        if !avatar_url.contains(".gif") {
+            return None;
+        }
+
+        if let Some(frames) = self.animated_avatars.lock().get(avatar_url) {
+            return Some(frames);
+        }
+
+        let bytes = fetch_bytes(hyper, avatar_url).await?;
+        let frames = self
+            .render(move || decode_gif_frames(&bytes))
+            .await
+            .ok()
+            .flatten()?;
+
+        self.animated_avatars
+            .lock()
+            .insert(avatar_url.to_owned(), frames.clone());
+
+        Some(frames)
+    }
+
+    pub async fn background(
+        &self,
+        guild_id: Id<GuildMarker>,
+        theme: &RankCardTheme,
+    ) -> Result<Image> {
+        let key = match &theme.background {
+            Some(bytes) => {
+                let mut hasher = DefaultHasher::new();
+
+                bytes.hash(&mut hasher);
+
+                format!("theme:{:x}", hasher.finish())
+            }
+            None => format!("guild:{guild_id}"),
+        };
+
+        if let Some(image) = self.backgrounds.lock().get(&key) {
+            return Ok(image);
+        }
+
+        let background = theme.background.clone();
+        let image = self
+            .render(move || {
+                background
+                    .as_deref()
+                    .and_then(decode_image)
+                    .unwrap_or_else(|| {
+                        let image = get_background_image(guild_id);
+
+                        image.make_raster_image(None, None).unwrap_or(image)
+                    })
+            })
+            .await?;
+
+        self.backgrounds.lock().insert(key, image.clone());
+
+        Ok(image)
+    }
+
+    pub async fn render<F, T>(
+        &self,
+        render: F,
+    ) -> Result<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let _permit = self.permits.acquire().await?;
+
+        Ok(spawn_blocking(render).await?)
+    }
+}
+
+fn decode_image(bytes: &[u8]) -> Option<Image> {
+    Image::from_encoded(Data::new_copy(bytes))?.make_raster_image(None, None)
+}
+
+async fn fetch_bytes(
+    hyper: &HyperClient<HttpsConnector<HttpConnector>>,
+    url: &str,
+) -> Option<Vec<u8>> {
+    let response = hyper.get(url.parse().ok()?).await.ok()?;
+
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let bytes = hyper::body::to_bytes(response.into_body()).await.ok()?;
+
+    Some(bytes.to_vec())
+}
+
+async fn fetch_image_bytes(
+    hyper: &HyperClient<HttpsConnector<HttpConnector>>,
+    url: &str,
+) -> Option<Vec<u8>> {
+    let bytes = fetch_bytes(hyper, url).await?;
+
+    image::guess_format(&bytes).is_ok().then_some(bytes)
+}
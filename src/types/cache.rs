@@ -5,11 +5,16 @@ use std::{
 
 use parking_lot::RwLock;
 use time::OffsetDateTime;
-use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
-    Id,
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
 };
 
+use crate::types::database::{LevelCurve, LevelUpMode, MultiplierMode, RoleStacking};
+
 pub struct Cache {
     pub channels: RwLock<HashMap<Id<ChannelMarker>, Arc<Channel>>>,
     pub guilds: RwLock<HashMap<Id<GuildMarker>, Arc<Guild>>>,
@@ -20,6 +25,7 @@ pub struct Cache {
 pub struct Channel {
     pub channel_id: Id<ChannelMarker>,
     pub guild_id: Id<GuildMarker>,
+    pub kind: ChannelType,
     pub user_ids: RwLock<HashSet<Id<UserMarker>>>,
 }
 
@@ -29,20 +35,66 @@ pub struct ChannelUpdate {
 }
 
 pub struct Guild {
+    pub afk_channel_id: RwLock<Option<Id<ChannelMarker>>>,
     pub channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
+    pub channel_multipliers: RwLock<HashMap<Id<ChannelMarker>, f64>>,
+    pub cooldown_seconds: RwLock<i64>,
     pub guild_id: Id<GuildMarker>,
+    pub ignored_role_ids: RwLock<HashSet<Id<RoleMarker>>>,
+    pub ignored_text_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
+    pub ignored_voice_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
+    pub level_curve: RwLock<LevelCurve>,
+    pub level_up_channel_id: RwLock<Option<Id<ChannelMarker>>>,
+    pub level_up_mode: RwLock<LevelUpMode>,
+    pub level_up_template: RwLock<String>,
     pub levels: RwLock<Vec<(u64, HashSet<Id<RoleMarker>>)>>,
+    pub max_xp: RwLock<i64>,
     pub member_ids: RwLock<HashSet<Id<UserMarker>>>,
+    pub min_xp: RwLock<i64>,
+    pub multiplier_mode: RwLock<MultiplierMode>,
     pub name: String,
+    pub role_multipliers: RwLock<HashMap<Id<RoleMarker>, f64>>,
+    pub role_names: RwLock<HashMap<Id<RoleMarker>, String>>,
+    pub role_stacking: RwLock<RoleStacking>,
+    pub voice_allow_muted: RwLock<bool>,
+    pub voice_audience_multiplier: RwLock<f64>,
+    pub voice_bonus_multiplier: RwLock<f64>,
+    pub voice_daily_xp_cap: RwLock<i64>,
+    pub voice_max_session_xp: RwLock<i64>,
+    pub voice_min_members: RwLock<i64>,
+    pub voice_xp_per_minute: RwLock<i64>,
     pub xp_multiplier: RwLock<f64>,
 }
 
 #[derive(Default)]
 pub struct GuildUpdate {
+    pub afk_channel_id: Option<Option<Id<ChannelMarker>>>,
     pub channel_ids: Option<HashSet<Id<ChannelMarker>>>,
+    pub channel_multipliers: Option<HashMap<Id<ChannelMarker>, f64>>,
+    pub cooldown_seconds: Option<i64>,
+    pub ignored_role_ids: Option<HashSet<Id<RoleMarker>>>,
+    pub ignored_text_channel_ids: Option<HashSet<Id<ChannelMarker>>>,
+    pub ignored_voice_channel_ids: Option<HashSet<Id<ChannelMarker>>>,
+    pub level_curve: Option<LevelCurve>,
+    pub level_up_channel_id: Option<Option<Id<ChannelMarker>>>,
+    pub level_up_mode: Option<LevelUpMode>,
+    pub level_up_template: Option<String>,
     pub levels: Option<Vec<(u64, HashSet<Id<RoleMarker>>)>>,
+    pub max_xp: Option<i64>,
     pub member_ids: Option<HashSet<Id<UserMarker>>>,
+    pub min_xp: Option<i64>,
+    pub multiplier_mode: Option<MultiplierMode>,
     pub name: Option<String>,
+    pub role_multipliers: Option<HashMap<Id<RoleMarker>, f64>>,
+    pub role_names: Option<HashMap<Id<RoleMarker>, String>>,
+    pub role_stacking: Option<RoleStacking>,
+    pub voice_allow_muted: Option<bool>,
+    pub voice_audience_multiplier: Option<f64>,
+    pub voice_bonus_multiplier: Option<f64>,
+    pub voice_daily_xp_cap: Option<i64>,
+    pub voice_max_session_xp: Option<i64>,
+    pub voice_min_members: Option<i64>,
+    pub voice_xp_per_minute: Option<i64>,
     pub xp_multiplier: Option<f64>,
 }
 
@@ -57,6 +109,8 @@ pub struct Member {
     pub user_id: Id<UserMarker>,
     pub username: String,
     pub voice_channel_id: RwLock<Option<Id<ChannelMarker>>>,
+    pub voice_session_xp: RwLock<i64>,
+    pub voice_status: RwLock<VoiceStatus>,
     pub xp: RwLock<i64>
 }
 
@@ -69,5 +123,16 @@ pub struct MemberUpdate {
     pub role_ids: Option<HashSet<Id<RoleMarker>>>,
     pub username: Option<String>,
     pub voice_channel_id: Option<Option<Id<ChannelMarker>>>,
+    pub voice_session_xp: Option<i64>,
+    pub voice_status: Option<VoiceStatus>,
     pub xp: Option<i64>
 }
+
+#[derive(Clone, Copy, Default)]
+pub struct VoiceStatus {
+    pub deafened: bool,
+    pub muted: bool,
+    pub streaming: bool,
+    pub suppressed: bool,
+    pub video: bool,
+}
@@ -7,7 +7,7 @@ use twilight_gateway::Latency;
 use twilight_http::Client as HttpClient;
 use twilight_model::id::{marker::ApplicationMarker, Id};
 
-use super::{cache::Cache, database::Database};
+use super::{cache::Cache, database::Database, render::Renderer};
 
 pub struct Context {
     pub application_id: Id<ApplicationMarker>,
@@ -17,4 +17,5 @@ pub struct Context {
     pub http: Arc<HttpClient>,
     pub hyper: HyperClient<HttpsConnector<HttpConnector>>,
     pub latencies: RwLock<HashMap<u64, Arc<Latency>>>,
+    pub renderer: Renderer,
 }
@@ -1,5 +1,120 @@
 use deadpool_postgres::Pool;
+use time::OffsetDateTime;
+use twilight_model::id::{marker::ChannelMarker, Id};
 
 pub struct Database {
     pub pool: Pool,
 }
+
+pub struct GuildSettings {
+    pub cooldown_seconds: i64,
+    pub level_curve: LevelCurve,
+    pub level_up_channel_id: Option<Id<ChannelMarker>>,
+    pub level_up_mode: LevelUpMode,
+    pub level_up_template: String,
+    pub max_xp: i64,
+    pub min_xp: i64,
+    pub multiplier_mode: MultiplierMode,
+    pub role_stacking: RoleStacking,
+    pub voice_allow_muted: bool,
+    pub voice_audience_multiplier: f64,
+    pub voice_bonus_multiplier: f64,
+    pub voice_daily_xp_cap: i64,
+    pub voice_max_session_xp: i64,
+    pub voice_min_members: i64,
+    pub voice_xp_per_minute: i64,
+    pub xp_multiplier: f64,
+}
+
+pub struct RankCardTheme {
+    pub accent_color: u32,
+    pub background: Option<Vec<u8>>,
+    pub bar_style: RankCardBarStyle,
+    pub font: RankCardFont,
+    pub overlay_opacity: i64,
+}
+
+#[derive(Default)]
+pub struct RankCardThemeUpdate {
+    pub accent_color: Option<u32>,
+    pub background: Option<Vec<u8>>,
+    pub bar_style: Option<RankCardBarStyle>,
+    pub font: Option<RankCardFont>,
+    pub overlay_opacity: Option<i64>,
+}
+
+pub struct Season {
+    pub archive_size: i64,
+    pub ended_at: Option<OffsetDateTime>,
+    pub ends_at: Option<OffsetDateTime>,
+    pub season_number: i64,
+    pub started_at: OffsetDateTime,
+}
+
+#[derive(Clone, Copy)]
+pub enum IgnoredKind {
+    Role,
+    TextChannel,
+    VoiceChannel,
+}
+
+#[derive(Clone, Default, PartialEq)]
+pub enum LevelCurve {
+    Custom(Vec<i64>),
+    Erratic,
+    Exponential {
+        base: f64,
+        growth: f64,
+    },
+    #[default]
+    Fluctuating,
+    Linear {
+        step: i64,
+    },
+    MediumFast,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum LevelUpMode {
+    Channel,
+    Current,
+    Direct,
+    #[default]
+    Off,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum MultiplierMode {
+    Max,
+    #[default]
+    Product,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum RankCardBarStyle {
+    #[default]
+    Rounded,
+    Square,
+    Thin,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum RankCardFont {
+    Monospace,
+    Serif,
+    #[default]
+    SourceSans3,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum RoleStacking {
+    HighestOnly,
+    #[default]
+    Stack,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum XpSource {
+    Message,
+    Voice,
+}
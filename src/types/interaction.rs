@@ -10,7 +10,7 @@ use twilight_model::{
     guild::Permissions,
     http::attachment::Attachment,
     id::{
-        marker::{InteractionMarker, UserMarker},
+        marker::{ChannelMarker, InteractionMarker, UserMarker},
         Id,
     },
 };
@@ -19,6 +19,7 @@ use crate::types::cache::Guild;
 
 pub struct ApplicationCommandInteraction<'a> {
     pub cached_guild: Arc<Guild>,
+    pub channel_id: Option<Id<ChannelMarker>>,
     pub context: InteractionContext<'a>,
     pub data: Box<CommandData>,
     pub shard_id: u64,
@@ -43,6 +44,7 @@ pub struct MessageComponentInteraction<'a> {
     pub data: MessageComponentInteractionData,
     pub message: Message,
     pub shard_id: u64,
+    pub user_id: Id<UserMarker>,
 }
 
 #[derive(Default)]
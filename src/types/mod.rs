@@ -4,5 +4,6 @@ pub mod cache;
 pub mod context;
 pub mod database;
 pub mod interaction;
+pub mod render;
 
 pub type Result<T> = std::result::Result<T, Error>;
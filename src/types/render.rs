@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use skia_safe::Image;
+use tokio::sync::Semaphore;
+
+pub struct ImageCache<T = Image> {
+    pub capacity: usize,
+    pub entries: HashMap<String, (T, u64)>,
+    pub tick: u64,
+}
+
+pub struct Renderer {
+    pub animated_avatars: Mutex<ImageCache<Vec<(Image, u32)>>>,
+    pub avatars: Mutex<ImageCache>,
+    pub backgrounds: Mutex<ImageCache>,
+    pub permits: Semaphore,
+}
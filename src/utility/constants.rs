@@ -79,7 +79,7 @@ pub static FLUCTUATING_XP: Lazy<Vec<(u64, i64, i64)>> = Lazy::new(|| {
         (71, 479_600, 28_017),
         (72, 507_617, 21_446),
         (73, 529_063, 30_146),
-        (74, 559_209, 22_9780),
+        (74, 559_209, 22_978),
         (75, 582_187, 32_379),
         (76, 614_566, 24_580),
         (77, 639_146, 34_717),
@@ -108,3 +108,19 @@ pub static FLUCTUATING_XP: Lazy<Vec<(u64, i64, i64)>> = Lazy::new(|| {
         (100, 1_640_000, 0),
     ]
 });
+pub const INTERACTION_RESPONSE_DEADLINE_SECONDS: u64 = 840;
+pub const LEADERBOARD_BUTTON_EXPIRY_SECONDS: i64 = 300;
+pub const MAX_ANIMATED_CARD_BYTES: usize = 8_000_000;
+pub const MAX_ANIMATED_CARD_ENCODE_ATTEMPTS: usize = 3;
+pub const MAX_ANIMATED_CARD_FRAMES: usize = 60;
+pub const MAX_BACKGROUND_BYTES: u64 = 8_000_000;
+pub const MAX_BACKGROUND_DIMENSION: u32 = 4_096;
+pub const MAX_LEVEL: u64 = 1_000;
+pub const MIN_BACKGROUND_HEIGHT: u32 = 125;
+pub const MIN_BACKGROUND_WIDTH: u32 = 437;
+pub const RENDER_ANIMATED_AVATAR_CACHE_SIZE: usize = 16;
+pub const RENDER_AVATAR_CACHE_SIZE: usize = 512;
+pub const RENDER_BACKGROUND_CACHE_SIZE: usize = 64;
+pub const RENDER_CONCURRENCY: usize = 4;
+pub const SEASON_CHECK_MINUTES: u64 = 1;
+pub const VOICE_CHECKPOINT_MINUTES: u64 = 5;
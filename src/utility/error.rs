@@ -2,6 +2,8 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error("Unable to acquire a render permit")]
+    Acquire(#[from] tokio::sync::AcquireError),
     #[error("Unable to build connection pool")]
     Build(#[from] deadpool_postgres::BuildError),
     #[error("Provided time component is out of range")]
@@ -18,6 +20,8 @@ pub enum Error {
     InvalidUri(#[from] http::uri::InvalidUri),
     #[error("Unable to open file or get current working directory")]
     Io(#[from] std::io::Error),
+    #[error("Unable to join blocking task")]
+    Join(#[from] tokio::task::JoinError),
     #[error("Unable to validate message")]
     MessageValidation(#[from] twilight_validate::message::MessageValidationError),
     #[error("Unable to parse integer")]
@@ -40,7 +40,9 @@ pub async fn connect(
         | EventTypeFlags::MEMBER_UPDATE
         | EventTypeFlags::MESSAGE_CREATE
         | EventTypeFlags::READY
+        | EventTypeFlags::ROLE_CREATE
         | EventTypeFlags::ROLE_DELETE
+        | EventTypeFlags::ROLE_UPDATE
         | EventTypeFlags::UNAVAILABLE_GUILD
         | EventTypeFlags::VOICE_STATE_UPDATE;
     let config = Config::builder(BOT_TOKEN.to_owned(), intents)
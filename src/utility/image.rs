@@ -1,59 +1,193 @@
-use std::{cmp, env::current_dir, fs};
+use std::{cmp::Ordering, env::current_dir, fs, io::Cursor};
 
+use image::{
+    codecs::gif::{GifDecoder, GifEncoder, Repeat},
+    imageops::FilterType,
+    io::Reader,
+    AnimationDecoder,
+    Delay,
+    DynamicImage,
+    Frame,
+    ImageFormat,
+    ImageOutputFormat,
+    RgbaImage,
+};
 use skia_safe::{
     surfaces::raster_n32_premul,
     utils::text_utils::Align,
+    AlphaType,
+    Canvas,
     ClipOp,
+    ColorType,
     Data,
     EncodedImageFormat,
     Font,
+    FontMgr,
+    FontStyle,
     Image,
+    ImageInfo,
     Paint,
     PaintStyle,
     Path,
     PathDirection,
     Point,
     Rect,
+    SrcRectConstraint,
     Typeface,
 };
+use thousands::Separable;
 use twilight_model::{
     http::attachment::Attachment,
-    id::{marker::GuildMarker, Id},
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+    util::ImageHash,
 };
 
-use crate::utility::{constants::FLUCTUATING_XP, decimal::abbreviate};
+use crate::{
+    types::database::{LevelCurve, RankCardBarStyle, RankCardFont, RankCardTheme},
+    utility::{
+        constants::{
+            MAX_ANIMATED_CARD_BYTES,
+            MAX_ANIMATED_CARD_ENCODE_ATTEMPTS,
+            MAX_ANIMATED_CARD_FRAMES,
+            MAX_BACKGROUND_DIMENSION,
+            MIN_BACKGROUND_HEIGHT,
+            MIN_BACKGROUND_WIDTH,
+        },
+        decimal::abbreviate,
+        leveling::{level_for_xp, progress_within_level, xp_to_next_level},
+    },
+};
 
-pub fn get_profile(
-    guild_id: Id<GuildMarker>,
-    avatar_image: Image,
-    username: String,
-    rank: usize,
-    xp: i64,
-) -> Attachment {
+pub struct Profile {
+    pub avatar_image: Option<Image>,
+    pub background_image: Image,
+    pub level_curve: LevelCurve,
+    pub message_xp: i64,
+    pub next_reward: Option<(u64, String)>,
+    pub rank: usize,
+    pub ranked_members: usize,
+    pub theme: RankCardTheme,
+    pub username: String,
+    pub voice_xp: i64,
+    pub xp: i64,
+}
+
+pub fn get_profile(profile: Profile) -> Attachment {
+    let avatar_image = profile.avatar_image.clone();
     let mut surface = raster_n32_premul((875i32, 250i32)).unwrap();
 
-    surface.canvas().save();
+    surface
+        .canvas()
+        .draw_image(draw_profile_base(&profile), (0, 0), None);
 
-    let cwd = current_dir().unwrap();
-    let background_image_path = format!(
-        "{}/assets/images/{}.png",
-        cwd.to_string_lossy(),
-        guild_id.get()
-    );
-    let background_image_bytes = fs::read(background_image_path).map_or(
-        fs::read(format!(
-            "{}/assets/images/default.png",
-            cwd.to_string_lossy()
-        ))
-        .unwrap(),
-        |bytes| bytes,
-    );
-    let background_image_data = Data::new_copy(&background_image_bytes);
-    let background_image = Image::from_encoded(background_image_data).unwrap();
+    if let Some(avatar_image) = avatar_image {
+        draw_profile_avatar(surface.canvas(), avatar_image);
+    }
 
-    surface.canvas().draw_image(background_image, (0, 0), None);
+    let bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .unwrap()
+        .as_bytes()
+        .to_owned();
 
-    surface.canvas().restore();
+    Attachment::from_bytes("profile.png".to_owned(), bytes, 1)
+}
+
+pub fn get_animated_profile(
+    profile: &Profile,
+    avatar_frames: Vec<(Image, u32)>,
+) -> Option<Attachment> {
+    let base_image = draw_profile_base(profile);
+    let mut surface = raster_n32_premul((875i32, 250i32))?;
+    let image_info = ImageInfo::new((875, 250), ColorType::RGBA8888, AlphaType::Unpremul, None);
+    let mut rendered_frames = Vec::new();
+
+    for (avatar_image, delay) in avatar_frames {
+        let mut pixels = vec![0u8; 875 * 250 * 4];
+
+        surface.canvas().draw_image(&base_image, (0, 0), None);
+        draw_profile_avatar(surface.canvas(), avatar_image);
+
+        if !surface.read_pixels(&image_info, &mut pixels, 875 * 4, (0, 0)) {
+            return None;
+        }
+
+        rendered_frames.push((pixels, delay));
+    }
+
+    let mut step = 1;
+
+    for _ in 0 .. MAX_ANIMATED_CARD_ENCODE_ATTEMPTS {
+        let frames = rendered_frames
+            .chunks(step)
+            .map(|chunk| {
+                (
+                    chunk[0].0.clone(),
+                    chunk.iter().map(|(_, delay)| delay).sum::<u32>(),
+                )
+            })
+            .collect::<Vec<(Vec<u8>, u32)>>();
+
+        if frames.len() < 2 {
+            return None;
+        }
+
+        let bytes = encode_gif(frames)?;
+
+        if bytes.len() <= MAX_ANIMATED_CARD_BYTES {
+            return Some(Attachment::from_bytes("profile.gif".to_owned(), bytes, 1));
+        }
+
+        step *= bytes.len().div_ceil(MAX_ANIMATED_CARD_BYTES).max(2);
+    }
+
+    None
+}
+
+pub fn decode_gif_frames(bytes: &[u8]) -> Option<Vec<(Image, u32)>> {
+    let decoder = GifDecoder::new(Cursor::new(bytes)).ok()?;
+    let mut frames = Vec::new();
+
+    for frame in decoder.into_frames().take(MAX_ANIMATED_CARD_FRAMES) {
+        let frame = frame.ok()?;
+        let (numerator, denominator) = frame.delay().numer_denom_ms();
+        let mut png_bytes = Vec::new();
+
+        DynamicImage::ImageRgba8(frame.into_buffer())
+            .write_to(&mut Cursor::new(&mut png_bytes), ImageOutputFormat::Png)
+            .ok()?;
+        frames.push((
+            Image::from_encoded(Data::new_copy(&png_bytes))?,
+            numerator / denominator.max(1),
+        ));
+    }
+
+    (frames.len() > 1).then_some(frames)
+}
+
+fn draw_profile_base(profile: &Profile) -> Image {
+    let Profile {
+        avatar_image: _,
+        background_image,
+        level_curve,
+        message_xp,
+        next_reward,
+        rank,
+        ranked_members,
+        theme,
+        username,
+        voice_xp,
+        xp,
+    } = profile;
+    let (message_xp, rank, ranked_members, voice_xp, xp) =
+        (*message_xp, *rank, *ranked_members, *voice_xp, *xp);
+    let mut surface = raster_n32_premul((875i32, 250i32)).unwrap();
+
+    draw_image_cover(surface.canvas(), background_image.clone(), 875.0, 250.0);
 
     let mut translucent_rect = Path::new();
 
@@ -71,11 +205,11 @@ pub fn get_profile(
 
     surface.canvas().draw_path(
         &translucent_rect,
-        Paint::default().set_style(PaintStyle::Fill).set_alpha(128),
+        Paint::default()
+            .set_style(PaintStyle::Fill)
+            .set_alpha(overlay_alpha(theme)),
     );
 
-    surface.canvas().save();
-
     surface.canvas().draw_circle(
         (125, 125),
         75.0,
@@ -84,154 +218,700 @@ pub fn get_profile(
             .set_color(0xF8F8FFFF),
     );
 
+    let mut font = get_font(theme.font);
+    let level = level_for_xp(level_curve, xp);
+    let (progress_text, progress_percentage) = get_level_progress(level_curve, xp);
+    let text_paint = Paint::default()
+        .set_style(PaintStyle::StrokeAndFill)
+        .set_argb(255, 248, 248, 255)
+        .to_owned();
+
+    surface.canvas().draw_str_align(
+        username,
+        Point::new(520.0, 72.5),
+        &font.set_size(38.0),
+        &text_paint,
+        Align::Center,
+    );
+
+    let total_source_xp = message_xp + voice_xp;
+    let message_share = if total_source_xp > 0 {
+        message_xp as f32 / total_source_xp as f32
+    } else {
+        1.0
+    };
+
+    surface.canvas().draw_str_align(
+        format!("Messages {:.0}%", message_share * 100.0),
+        Point::new(270.0, 105.0),
+        &font.set_size(16.0),
+        &text_paint,
+        Align::Left,
+    );
+
+    surface.canvas().draw_str_align(
+        format!("Voice {:.0}%", (1.0 - message_share) * 100.0),
+        Point::new(770.0, 105.0),
+        &font.set_size(16.0),
+        &text_paint,
+        Align::Right,
+    );
+
+    surface.canvas().draw_round_rect(
+        Rect::from_xywh(270.0, 111.0, 500.0, 6.0),
+        3.0,
+        3.0,
+        Paint::default()
+            .set_style(PaintStyle::Fill)
+            .set_argb(255, 248, 248, 255),
+    );
+
+    surface.canvas().save();
+
     surface
         .canvas()
         .clip_path(
-            Path::new().add_circle((125, 125), 70.0, PathDirection::CCW),
+            Path::new().add_round_rect(
+                Rect::from_xywh(270.0, 111.0, 500.0, 6.0),
+                (3.0, 3.0),
+                PathDirection::CW,
+            ),
             Some(ClipOp::Intersect),
             Some(true),
         )
-        .draw_image_rect(
-            avatar_image,
-            None,
-            Rect {
-                bottom: 195.0,
-                left: 55.0,
-                right: 195.0,
-                top: 55.0,
-            },
-            Paint::default().set_style(PaintStyle::Fill),
+        .draw_rect(
+            Rect::from_xywh(270.0, 111.0, 500.0 * message_share, 6.0),
+            Paint::default()
+                .set_style(PaintStyle::Fill)
+                .set_color(0xFF000000 | theme.accent_color),
         );
 
     surface.canvas().restore();
 
-    let typeface_bytes = fs::read(format!(
-        "{}/assets/fonts/SourceSans3-SemiBold.ttf",
-        cwd.to_string_lossy()
-    ))
-    .unwrap();
-    let typeface_data = Data::new_copy(&typeface_bytes);
-    let mut source_sans_3 = Font::new(Typeface::from_data(typeface_data, None).unwrap(), None);
-    let (level_text, progress_text, progress_percentage) =
-        match FLUCTUATING_XP.iter().position(|&level| xp.lt(&level.1)) {
-            None => ("Lv. 100".to_owned(), "MAX LEVEL".to_owned(), 100.0),
-            Some(position) => {
-                let index = cmp::max(position - 1, 0);
-                let (level, total_xp, xp_to_next_level) = FLUCTUATING_XP[index];
+    surface.canvas().draw_str_align(
+        format!("Rank #{rank} of {ranked_members} (Lv. {level})"),
+        Point::new(270.0, 145.0),
+        &font.set_size(20.0),
+        &text_paint,
+        Align::Left,
+    );
 
-                (
-                    format!("Lv. {level}"),
-                    format!(
-                        "{} / {}",
-                        abbreviate(xp - total_xp),
-                        abbreviate(xp_to_next_level)
-                    ),
-                    (100.0 * ((xp - total_xp) as f32)) / (xp_to_next_level as f32),
+    surface.canvas().draw_str_align(
+        progress_text,
+        Point::new(770.0, 145.0),
+        &font.set_size(20.0),
+        &text_paint,
+        Align::Right,
+    );
+
+    draw_progress_bar(
+        surface.canvas(),
+        theme,
+        Rect::from_xywh(265.0, 152.0, 510.0, 30.0),
+        progress_percentage,
+    );
+
+    let next_level_text = match xp_to_next_level(level_curve, xp) {
+        Some(remaining_xp) => format!(
+            "{} XP to Lv. {}",
+            remaining_xp.separate_with_commas(),
+            level + 1
+        ),
+        None => "Maximum level reached".to_owned(),
+    };
+
+    surface.canvas().draw_str_align(
+        next_level_text,
+        Point::new(270.0, 210.0),
+        &font.set_size(18.0),
+        &text_paint,
+        Align::Left,
+    );
+
+    if let Some((reward_level, reward_role_name)) = next_reward {
+        surface.canvas().draw_str_align(
+            format!("Next reward: {reward_role_name} at Lv. {reward_level}"),
+            Point::new(770.0, 210.0),
+            &font.set_size(18.0),
+            &text_paint,
+            Align::Right,
+        );
+    }
+
+    surface.image_snapshot()
+}
+
+pub struct LeaderboardImageRow {
+    pub avatar_image: Option<Image>,
+    pub rank: usize,
+    pub total_xp: i64,
+    pub username: String,
+    pub xp_text: String,
+}
+
+pub fn get_leaderboard_image(
+    background_image: Image,
+    level_curve: LevelCurve,
+    theme: &RankCardTheme,
+    title: String,
+    rows: Vec<LeaderboardImageRow>,
+) -> Attachment {
+    let height = 100.0 + (rows.len() as f32 * 75.0);
+    let mut surface = raster_n32_premul((875i32, height as i32)).unwrap();
+
+    draw_image_cover(surface.canvas(), background_image, 875.0, height);
+
+    let mut font = get_font(theme.font);
+    let text_paint = Paint::default()
+        .set_style(PaintStyle::StrokeAndFill)
+        .set_argb(255, 248, 248, 255)
+        .to_owned();
+
+    surface.canvas().draw_str_align(
+        title,
+        Point::new(437.5, 55.0),
+        &font.set_size(34.0),
+        &text_paint,
+        Align::Center,
+    );
+
+    for (index, row) in rows.into_iter().enumerate() {
+        let top = 80.0 + (index as f32 * 75.0);
+
+        surface.canvas().draw_round_rect(
+            Rect::from_xywh(20.0, top, 835.0, 65.0),
+            20.0,
+            20.0,
+            Paint::default()
+                .set_style(PaintStyle::Fill)
+                .set_alpha(overlay_alpha(theme)),
+        );
+
+        surface.canvas().draw_str_align(
+            format!("#{}", row.rank),
+            Point::new(80.0, top + 42.0),
+            &font.set_size(24.0),
+            &text_paint,
+            Align::Right,
+        );
+
+        surface.canvas().draw_circle(
+            (122.5, top + 32.5),
+            27.5,
+            Paint::default()
+                .set_style(PaintStyle::Fill)
+                .set_color(0xF8F8FFFF),
+        );
+
+        if let Some(avatar_image) = row.avatar_image {
+            surface.canvas().save();
+
+            surface
+                .canvas()
+                .clip_path(
+                    Path::new().add_circle((122.5, top + 32.5), 25.0, PathDirection::CCW),
+                    Some(ClipOp::Intersect),
+                    Some(true),
                 )
-            }
+                .draw_image_rect(
+                    avatar_image,
+                    None,
+                    Rect::from_xywh(97.5, top + 7.5, 50.0, 50.0),
+                    Paint::default().set_style(PaintStyle::Fill),
+                );
+
+            surface.canvas().restore();
+        }
+
+        let username = if row.username.chars().count() > 24 {
+            format!("{}…", row.username.chars().take(23).collect::<String>())
+        } else {
+            row.username
         };
+        let (_, progress_percentage) = get_level_progress(&level_curve, row.total_xp);
+
+        surface.canvas().draw_str_align(
+            username,
+            Point::new(165.0, top + 42.0),
+            &font.set_size(26.0),
+            &text_paint,
+            Align::Left,
+        );
+
+        surface.canvas().draw_str_align(
+            format!("Lv. {}", level_for_xp(&level_curve, row.total_xp)),
+            Point::new(560.0, top + 28.0),
+            &font.set_size(18.0),
+            &text_paint,
+            Align::Left,
+        );
+
+        surface.canvas().draw_str_align(
+            row.xp_text,
+            Point::new(835.0, top + 28.0),
+            &font.set_size(18.0),
+            &text_paint,
+            Align::Right,
+        );
+
+        draw_progress_bar(
+            surface.canvas(),
+            theme,
+            Rect::from_xywh(560.0, top + 36.0, 275.0, 14.0),
+            progress_percentage,
+        );
+    }
+
+    let bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .unwrap()
+        .as_bytes()
+        .to_owned();
+
+    Attachment::from_bytes("leaderboard.png".to_owned(), bytes, 1)
+}
+
+pub struct ComparisonMember {
+    pub avatar_image: Option<Image>,
+    pub rank: usize,
+    pub username: String,
+    pub xp: i64,
+}
+
+pub fn get_comparison_image(
+    background_image: Image,
+    level_curve: LevelCurve,
+    theme: &RankCardTheme,
+    members: [ComparisonMember; 2],
+) -> Attachment {
+    let mut surface = raster_n32_premul((875i32, 300i32)).unwrap();
+
+    draw_image_cover(surface.canvas(), background_image, 875.0, 300.0);
+
+    surface.canvas().draw_round_rect(
+        Rect::from_xywh(20.0, 20.0, 835.0, 260.0),
+        50.0,
+        50.0,
+        Paint::default()
+            .set_style(PaintStyle::Fill)
+            .set_alpha(overlay_alpha(theme)),
+    );
+
+    let mut font = get_font(theme.font);
+    let text_paint = Paint::default()
+        .set_style(PaintStyle::StrokeAndFill)
+        .set_argb(255, 248, 248, 255)
+        .to_owned();
+    let [member_a, member_b] = members;
+    let (xp_a, xp_b) = (member_a.xp, member_b.xp);
+    let (level_a, level_b) = (
+        level_for_xp(&level_curve, xp_a),
+        level_for_xp(&level_curve, xp_b),
+    );
+    let gap_text = match xp_a.cmp(&xp_b) {
+        Ordering::Greater => format!("{} is ahead", member_a.username),
+        Ordering::Less => format!("{} is ahead", member_b.username),
+        Ordering::Equal => "Both members are tied".to_owned(),
+    };
+
+    for (center_x, member, level) in [(125.0, member_a, level_a), (750.0, member_b, level_b)] {
+        surface.canvas().draw_circle(
+            (center_x, 100.0),
+            65.0,
+            Paint::default()
+                .set_style(PaintStyle::Fill)
+                .set_color(0xF8F8FFFF),
+        );
+
+        if let Some(avatar_image) = member.avatar_image {
+            surface.canvas().save();
+
+            surface
+                .canvas()
+                .clip_path(
+                    Path::new().add_circle((center_x, 100.0), 60.0, PathDirection::CCW),
+                    Some(ClipOp::Intersect),
+                    Some(true),
+                )
+                .draw_image_rect(
+                    avatar_image,
+                    None,
+                    Rect::from_xywh(center_x - 60.0, 40.0, 120.0, 120.0),
+                    Paint::default().set_style(PaintStyle::Fill),
+                );
+
+            surface.canvas().restore();
+        }
+
+        let username = if member.username.chars().count() > 14 {
+            format!("{}…", member.username.chars().take(13).collect::<String>())
+        } else {
+            member.username
+        };
+
+        surface.canvas().draw_str_align(
+            username,
+            Point::new(center_x, 200.0),
+            &font.set_size(24.0),
+            &text_paint,
+            Align::Center,
+        );
+
+        surface.canvas().draw_str_align(
+            format!("Rank #{} (Lv. {level})", member.rank),
+            Point::new(center_x, 230.0),
+            &font.set_size(18.0),
+            &text_paint,
+            Align::Center,
+        );
+
+        surface.canvas().draw_str_align(
+            format!("{} XP", member.xp.separate_with_commas()),
+            Point::new(center_x, 256.0),
+            &font.set_size(16.0),
+            &text_paint,
+            Align::Center,
+        );
+    }
 
     surface.canvas().draw_str_align(
-        username,
-        Point::new(520.0, 87.5),
-        &source_sans_3.set_size(40.0),
-        &Paint::default()
-            .set_style(PaintStyle::StrokeAndFill)
-            .set_argb(255, 248, 248, 255),
+        "XP gap",
+        Point::new(437.5, 70.0),
+        &font.set_size(20.0),
+        &text_paint,
         Align::Center,
     );
 
     surface.canvas().draw_str_align(
-        format!("Rank #{rank} ({level_text})"),
-        Point::new(270.0, 140.0),
-        &source_sans_3.set_size(20.0),
-        &Paint::default()
-            .set_style(PaintStyle::StrokeAndFill)
-            .set_argb(255, 248, 248, 255),
+        format!("{} XP", (xp_a - xp_b).abs().separate_with_commas()),
+        Point::new(437.5, 120.0),
+        &font.set_size(44.0),
+        &text_paint,
+        Align::Center,
+    );
+
+    surface.canvas().draw_str_align(
+        gap_text,
+        Point::new(437.5, 152.0),
+        &font.set_size(18.0),
+        &text_paint,
+        Align::Center,
+    );
+
+    let share_a = if xp_a + xp_b > 0 {
+        xp_a as f32 / (xp_a + xp_b) as f32
+    } else {
+        0.5
+    };
+
+    surface.canvas().draw_str_align(
+        format!("{:.0}%", share_a * 100.0),
+        Point::new(235.0, 190.0),
+        &font.set_size(16.0),
+        &text_paint,
         Align::Left,
     );
 
     surface.canvas().draw_str_align(
-        progress_text,
-        Point::new(770.0, 140.0),
-        &source_sans_3.set_size(20.0),
-        &Paint::default()
-            .set_style(PaintStyle::StrokeAndFill)
-            .set_argb(255, 248, 248, 255),
+        format!("{:.0}%", (1.0 - share_a) * 100.0),
+        Point::new(640.0, 190.0),
+        &font.set_size(16.0),
+        &text_paint,
         Align::Right,
     );
 
-    surface.canvas().save();
+    draw_progress_bar(
+        surface.canvas(),
+        theme,
+        Rect::from_xywh(230.0, 198.0, 415.0, 26.0),
+        share_a * 100.0,
+    );
 
-    let mut outer_progress_bar = Path::new();
+    surface.canvas().draw_str_align(
+        format!("Lv. {level_a} vs Lv. {level_b}"),
+        Point::new(437.5, 256.0),
+        &font.set_size(18.0),
+        &text_paint,
+        Align::Center,
+    );
 
-    outer_progress_bar
-        .move_to((280, 150))
-        .line_to((760, 150))
-        .quad_to((775, 150), (775, 165))
-        .quad_to((775, 180), (760, 180))
-        .line_to((280, 180))
-        .quad_to((265, 180), (265, 165))
-        .quad_to((265, 150), (280, 150))
-        .close();
+    let bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .unwrap()
+        .as_bytes()
+        .to_owned();
 
-    surface.canvas().draw_path(
-        &outer_progress_bar,
-        Paint::default()
-            .set_style(PaintStyle::Fill)
-            .set_argb(255, 248, 248, 255),
-    );
+    Attachment::from_bytes("comparison.png".to_owned(), bytes, 1)
+}
 
-    surface.canvas().restore();
+pub fn default_avatar_url(user_id: Id<UserMarker>, discriminator: u16) -> String {
+    let index = if discriminator == 0 {
+        (user_id.get() >> 22) % 6
+    } else {
+        (discriminator % 5) as u64
+    };
 
-    let mut left_inner_progress_bar = Path::new();
-    let mut right_inner_progress_bar = Path::new();
-    let displacement = 500.0 - (progress_percentage * 5.0);
-
-    left_inner_progress_bar
-        .move_to((280, 155))
-        .line_to((760, 155))
-        .quad_to((770, 155), (770, 165))
-        .quad_to((770, 175), (760, 175))
-        .line_to((280, 175))
-        .quad_to((270, 175), (270, 165))
-        .quad_to((270, 155), (280, 155))
-        .close();
+    format!("https://cdn.discordapp.com/embed/avatars/{index}.png")
+}
 
-    right_inner_progress_bar
-        .move_to((280.0 - displacement, 155.0))
-        .line_to((760.0 - displacement, 155.0))
-        .quad_to((770.0 - displacement, 155.0), (770.0 - displacement, 165.0))
-        .quad_to((770.0 - displacement, 175.0), (760.0 - displacement, 175.0))
-        .line_to((280.0 - displacement, 175.0))
-        .quad_to((270.0 - displacement, 175.0), (270.0 - displacement, 165.0))
-        .quad_to((270.0 - displacement, 155.0), (280.0 - displacement, 155.0))
-        .close();
+pub fn member_avatar_url(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    member_avatar: Option<ImageHash>,
+    user_avatar: Option<ImageHash>,
+    discriminator: u16,
+) -> String {
+    if let Some(member_avatar) = member_avatar {
+        format!(
+            "https://cdn.discordapp.com/guilds/{guild_id}/users/{user_id}/avatars/{member_avatar}.{}",
+            avatar_extension(member_avatar)
+        )
+    } else if let Some(user_avatar) = user_avatar {
+        format!(
+            "https://cdn.discordapp.com/avatars/{user_id}/{user_avatar}.{}",
+            avatar_extension(user_avatar)
+        )
+    } else {
+        default_avatar_url(user_id, discriminator)
+    }
+}
 
-    surface
-        .canvas()
+pub fn fit_background(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let invalid_format = || "The background must be a PNG, JPEG, GIF or WebP image.".to_owned();
+    let format = image::guess_format(bytes).map_err(|_| invalid_format())?;
+
+    if !matches!(
+        format,
+        ImageFormat::Gif | ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP
+    ) {
+        return Err(invalid_format());
+    }
+
+    let (width, height) = Reader::with_format(Cursor::new(bytes), format)
+        .into_dimensions()
+        .map_err(|_| invalid_format())?;
+
+    if width < MIN_BACKGROUND_WIDTH || height < MIN_BACKGROUND_HEIGHT {
+        return Err(format!(
+            "The background must be at least {MIN_BACKGROUND_WIDTH}x{MIN_BACKGROUND_HEIGHT} \
+             pixels, but it is {width}x{height}."
+        ));
+    }
+
+    if width > MAX_BACKGROUND_DIMENSION || height > MAX_BACKGROUND_DIMENSION {
+        return Err(format!(
+            "The background must be at most {MAX_BACKGROUND_DIMENSION}x\
+             {MAX_BACKGROUND_DIMENSION} pixels, but it is {width}x{height}."
+        ));
+    }
+
+    let background = image::load_from_memory_with_format(bytes, format)
+        .map_err(|_| invalid_format())?
+        .resize_to_fill(875, 250, FilterType::Lanczos3);
+    let mut background_bytes = Vec::new();
+
+    background
+        .write_to(&mut Cursor::new(&mut background_bytes), ImageOutputFormat::Png)
+        .map_err(|_| invalid_format())?;
+
+    Ok(background_bytes)
+}
+
+fn avatar_extension(avatar: ImageHash) -> &'static str {
+    if avatar.is_animated() {
+        "gif"
+    } else {
+        "png"
+    }
+}
+
+fn draw_profile_avatar(canvas: &mut Canvas, avatar_image: Image) {
+    canvas.save();
+
+    canvas
         .clip_path(
-            &left_inner_progress_bar,
+            Path::new().add_circle((125, 125), 70.0, PathDirection::CCW),
             Some(ClipOp::Intersect),
             Some(true),
         )
+        .draw_image_rect(
+            avatar_image,
+            None,
+            Rect {
+                bottom: 195.0,
+                left: 55.0,
+                right: 195.0,
+                top: 55.0,
+            },
+            Paint::default().set_style(PaintStyle::Fill),
+        );
+
+    canvas.restore();
+}
+
+fn draw_image_cover(canvas: &mut Canvas, image: Image, width: f32, height: f32) {
+    let (image_width, image_height) = (image.width() as f32, image.height() as f32);
+    let cropped_width = image_width.min(image_height * width / height);
+    let cropped_height = image_height.min(image_width * height / width);
+    let cropped_left = (image_width - cropped_width) / 2.0;
+    let cropped_top = (image_height - cropped_height) / 2.0;
+
+    canvas.draw_image_rect(
+        image,
+        Some((
+            &Rect::from_xywh(cropped_left, cropped_top, cropped_width, cropped_height),
+            SrcRectConstraint::Fast,
+        )),
+        Rect::from_xywh(0.0, 0.0, width, height),
+        &Paint::default(),
+    );
+}
+
+fn draw_progress_bar(
+    canvas: &mut Canvas,
+    theme: &RankCardTheme,
+    bounds: Rect,
+    progress_percentage: f32,
+) {
+    let outer = match theme.bar_style {
+        RankCardBarStyle::Thin => Rect::from_xywh(
+            bounds.left,
+            bounds.center_y() - (bounds.height() * 0.2),
+            bounds.width(),
+            bounds.height() * 0.4,
+        ),
+        _ => bounds,
+    };
+    let inset = outer.height() / 6.0;
+    let inner = Rect::from_ltrb(
+        outer.left + inset,
+        outer.top + inset,
+        outer.right - inset,
+        outer.bottom - inset,
+    );
+    let (outer_radius, inner_radius) = match theme.bar_style {
+        RankCardBarStyle::Square => (0.0, 0.0),
+        _ => (outer.height() / 2.0, inner.height() / 2.0),
+    };
+
+    canvas.draw_round_rect(
+        outer,
+        outer_radius,
+        outer_radius,
+        Paint::default()
+            .set_style(PaintStyle::Fill)
+            .set_argb(255, 248, 248, 255),
+    );
+
+    canvas.save();
+
+    canvas
         .clip_path(
-            &right_inner_progress_bar,
+            Path::new().add_round_rect(inner, (inner_radius, inner_radius), PathDirection::CW),
             Some(ClipOp::Intersect),
             Some(true),
         )
-        .draw_paint(
+        .draw_rect(
+            Rect::from_xywh(
+                inner.left,
+                inner.top,
+                inner.width() * progress_percentage / 100.0,
+                inner.height(),
+            ),
             Paint::default()
-                .set_style(PaintStyle::StrokeAndFill)
-                .set_argb(255, 201, 173, 127),
+                .set_style(PaintStyle::Fill)
+                .set_color(0xFF000000 | theme.accent_color),
         );
 
-    let bytes = surface
-        .image_snapshot()
-        .encode(None, EncodedImageFormat::PNG, None)
-        .unwrap()
-        .as_bytes()
-        .to_owned();
+    canvas.restore();
+}
 
-    Attachment::from_bytes("profile.png".to_owned(), bytes, 1)
+pub fn get_background_image(guild_id: Id<GuildMarker>) -> Image {
+    let cwd = current_dir().unwrap();
+    let background_image_path = format!(
+        "{}/assets/images/{}.png",
+        cwd.to_string_lossy(),
+        guild_id.get()
+    );
+    let background_image_bytes = fs::read(background_image_path).map_or(
+        fs::read(format!(
+            "{}/assets/images/default.png",
+            cwd.to_string_lossy()
+        ))
+        .unwrap(),
+        |bytes| bytes,
+    );
+    let background_image_data = Data::new_copy(&background_image_bytes);
+
+    Image::from_encoded(background_image_data).unwrap()
+}
+
+fn get_font(font: RankCardFont) -> Font {
+    let family = match font {
+        RankCardFont::Monospace => Some("monospace"),
+        RankCardFont::Serif => Some("serif"),
+        RankCardFont::SourceSans3 => None,
+    };
+
+    if let Some(typeface) =
+        family.and_then(|family| FontMgr::default().match_family_style(family, FontStyle::bold()))
+    {
+        return Font::new(typeface, None);
+    }
+
+    let cwd = current_dir().unwrap();
+    let typeface_bytes = fs::read(format!(
+        "{}/assets/fonts/SourceSans3-SemiBold.ttf",
+        cwd.to_string_lossy()
+    ))
+    .unwrap();
+    let typeface_data = Data::new_copy(&typeface_bytes);
+
+    Font::new(Typeface::from_data(typeface_data, None).unwrap(), None)
+}
+
+fn encode_gif(frames: Vec<(Vec<u8>, u32)>) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut bytes, 10);
+
+        encoder.set_repeat(Repeat::Infinite).ok()?;
+
+        for (pixels, delay) in frames {
+            let buffer = RgbaImage::from_raw(875, 250, pixels)?;
+
+            encoder
+                .encode_frame(Frame::from_parts(
+                    buffer,
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(delay.max(20), 1),
+                ))
+                .ok()?;
+        }
+    }
+
+    Some(bytes)
+}
+
+fn get_level_progress(level_curve: &LevelCurve, xp: i64) -> (String, f32) {
+    match xp_to_next_level(level_curve, xp) {
+        None => ("MAX LEVEL".to_owned(), 100.0),
+        Some(remaining_xp) => {
+            let progress_xp = progress_within_level(level_curve, xp);
+            let level_xp = progress_xp + remaining_xp;
+
+            (
+                format!("{} / {}", abbreviate(progress_xp), abbreviate(level_xp)),
+                (100.0 * (progress_xp as f32)) / (level_xp as f32),
+            )
+        }
+    }
+}
+
+fn overlay_alpha(theme: &RankCardTheme) -> u8 {
+    (theme.overlay_opacity.clamp(0, 100) as f32 * 2.55).round() as u8
 }
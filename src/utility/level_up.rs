@@ -0,0 +1,101 @@
+use thousands::Separable;
+use twilight_model::{
+    channel::message::AllowedMentions,
+    id::{
+        marker::{ChannelMarker, UserMarker},
+        Id,
+    },
+};
+use twilight_validate::message::MESSAGE_CONTENT_LENGTH_MAX;
+
+use crate::types::{cache::Guild, context::Context, database::LevelUpMode, Result};
+
+pub async fn announce_level_up(
+    context: &Context,
+    guild: &Guild,
+    current_channel_id: Id<ChannelMarker>,
+    user_id: Id<UserMarker>,
+    level: u64,
+    xp: i64,
+) -> Result<()> {
+    let level_up_mode = *guild.level_up_mode.read();
+    let channel_id = match level_up_mode {
+        LevelUpMode::Channel => {
+            let Some(level_up_channel_id) = *guild.level_up_channel_id.read() else {
+                return Ok(())
+            };
+
+            level_up_channel_id
+        }
+        LevelUpMode::Current => current_channel_id,
+        LevelUpMode::Direct => {
+            let Ok(response) = context.http.create_private_channel(user_id).await else {
+                return Ok(())
+            };
+
+            response.model().await?.id
+        }
+        LevelUpMode::Off => return Ok(()),
+    };
+    let next_role = guild
+        .levels
+        .read()
+        .iter()
+        .filter(|(role_level, role_ids)| role_level.gt(&level) && !role_ids.is_empty())
+        .min_by_key(|(role_level, _)| *role_level)
+        .map_or("none".to_owned(), |(_, role_ids)| {
+            role_ids
+                .iter()
+                .map(|role_id| format!("<@&{role_id}>"))
+                .collect::<Vec<String>>()
+                .join(" ")
+        });
+    let content = render_level_up_template(
+        guild.level_up_template.read().as_str(),
+        user_id,
+        level,
+        xp,
+        next_role,
+    );
+    let content = if content.chars().count() > MESSAGE_CONTENT_LENGTH_MAX {
+        format!(
+            "{}…",
+            content
+                .chars()
+                .take(MESSAGE_CONTENT_LENGTH_MAX - 1)
+                .collect::<String>()
+        )
+    } else {
+        content
+    };
+    let allowed_mentions = AllowedMentions {
+        users: vec![user_id],
+        ..Default::default()
+    };
+    let Ok(request) = context
+        .http
+        .create_message(channel_id)
+        .allowed_mentions(Some(&allowed_mentions))
+        .content(&content)
+    else {
+        return Ok(())
+    };
+
+    request.await.ok();
+
+    Ok(())
+}
+
+pub fn render_level_up_template(
+    template: &str,
+    user_id: Id<UserMarker>,
+    level: u64,
+    xp: i64,
+    next_role: String,
+) -> String {
+    template
+        .replace("{user}", &format!("<@{user_id}>"))
+        .replace("{level}", &level.to_string())
+        .replace("{xp}", &xp.separate_with_commas())
+        .replace("{next_role}", &next_role)
+}
@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+
+use twilight_model::id::{marker::RoleMarker, Id};
+
+use crate::{
+    types::database::{LevelCurve, RoleStacking},
+    utility::constants::{FLUCTUATING_XP, MAX_LEVEL},
+};
+
+pub fn level_for_xp(
+    level_curve: &LevelCurve,
+    xp: i64,
+) -> u64 {
+    let mut low = 1;
+    let mut high = max_level(level_curve);
+
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+
+        if xp_for_level(level_curve, middle).le(&xp) {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    low
+}
+
+pub fn max_level(level_curve: &LevelCurve) -> u64 {
+    match level_curve {
+        LevelCurve::Custom(table) => (table.len() as u64).clamp(1, MAX_LEVEL),
+        LevelCurve::Erratic | LevelCurve::Fluctuating => 100,
+        LevelCurve::Exponential {
+            ..
+        }
+        | LevelCurve::Linear {
+            ..
+        }
+        | LevelCurve::MediumFast => MAX_LEVEL,
+    }
+}
+
+pub fn member_role_ids(
+    role_ids: &HashSet<Id<RoleMarker>>,
+    levels: &[(u64, HashSet<Id<RoleMarker>>)],
+    level: u64,
+    role_stacking: RoleStacking,
+) -> HashSet<Id<RoleMarker>> {
+    let mut member_role_ids = role_ids.clone();
+
+    match role_stacking {
+        RoleStacking::HighestOnly => {
+            for (_, level_role_ids) in levels.iter() {
+                for role_id in level_role_ids.iter() {
+                    member_role_ids.remove(role_id);
+                }
+            }
+
+            if let Some((_, level_role_ids)) = levels
+                .iter()
+                .filter(|(role_level, level_role_ids)| {
+                    role_level.le(&level) && !level_role_ids.is_empty()
+                })
+                .max_by_key(|(role_level, _)| *role_level)
+            {
+                member_role_ids.extend(level_role_ids.iter().copied());
+            }
+        }
+        RoleStacking::Stack => {
+            for (_, level_role_ids) in levels
+                .iter()
+                .filter(|(role_level, _)| role_level.le(&level))
+            {
+                member_role_ids.extend(level_role_ids.iter().copied());
+            }
+        }
+    }
+
+    member_role_ids
+}
+
+pub fn progress_within_level(
+    level_curve: &LevelCurve,
+    xp: i64,
+) -> i64 {
+    (xp - xp_for_level(level_curve, level_for_xp(level_curve, xp))).max(0)
+}
+
+pub fn xp_for_level(
+    level_curve: &LevelCurve,
+    level: u64,
+) -> i64 {
+    let level = level.min(max_level(level_curve));
+
+    if level <= 1 {
+        return 0;
+    }
+
+    let n = level as i64;
+
+    match level_curve {
+        LevelCurve::Custom(table) => table[(level - 1) as usize],
+        LevelCurve::Erratic => match n {
+            2 ..= 49 => n.pow(3) * (100 - n) / 50,
+            50 ..= 67 => n.pow(3) * (150 - n) / 100,
+            68 ..= 97 => n.pow(3) * ((1_911 - 10 * n) / 3) / 500,
+            _ => n.pow(3) * (160 - n) / 100,
+        },
+        LevelCurve::Exponential {
+            base,
+            growth,
+        } => (base * (growth.powi((n - 1) as i32) - 1.0) / (growth - 1.0)).floor() as i64,
+        LevelCurve::Fluctuating => FLUCTUATING_XP[(level - 1) as usize].1,
+        LevelCurve::Linear {
+            step,
+        } => step.saturating_mul(n - 1),
+        LevelCurve::MediumFast => n.pow(3),
+    }
+}
+
+pub fn xp_to_next_level(
+    level_curve: &LevelCurve,
+    xp: i64,
+) -> Option<i64> {
+    let level = level_for_xp(level_curve, xp);
+
+    if level.ge(&max_level(level_curve)) {
+        return None;
+    }
+
+    Some(xp_for_level(level_curve, level + 1) - xp.max(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use twilight_model::id::{marker::RoleMarker, Id};
+
+    use super::{
+        level_for_xp,
+        max_level,
+        member_role_ids,
+        progress_within_level,
+        xp_for_level,
+        xp_to_next_level,
+    };
+    use crate::types::database::{LevelCurve, RoleStacking};
+
+    fn levels() -> Vec<(u64, HashSet<Id<RoleMarker>>)> {
+        vec![
+            (5, HashSet::from([Id::new(5)])),
+            (10, HashSet::from([Id::new(10)])),
+            (20, HashSet::from([Id::new(20)])),
+        ]
+    }
+
+    #[test]
+    fn level_for_xp_handles_negative_xp() {
+        let level_curve = LevelCurve::Fluctuating;
+
+        assert_eq!(level_for_xp(&level_curve, -1), 1);
+        assert_eq!(level_for_xp(&level_curve, i64::MIN), 1);
+    }
+
+    #[test]
+    fn level_for_xp_handles_level_1_boundary() {
+        let level_curve = LevelCurve::Fluctuating;
+
+        assert_eq!(level_for_xp(&level_curve, 0), 1);
+        assert_eq!(level_for_xp(&level_curve, 3), 1);
+        assert_eq!(level_for_xp(&level_curve, 4), 2);
+    }
+
+    #[test]
+    fn level_for_xp_handles_level_100_boundary() {
+        let level_curve = LevelCurve::Fluctuating;
+
+        assert_eq!(level_for_xp(&level_curve, 1_639_999), 99);
+        assert_eq!(level_for_xp(&level_curve, 1_640_000), 100);
+        assert_eq!(level_for_xp(&level_curve, i64::MAX), 100);
+    }
+
+    #[test]
+    fn xp_for_level_matches_table() {
+        let level_curve = LevelCurve::Fluctuating;
+
+        assert_eq!(xp_for_level(&level_curve, 0), 0);
+        assert_eq!(xp_for_level(&level_curve, 1), 0);
+        assert_eq!(xp_for_level(&level_curve, 2), 4);
+        assert_eq!(xp_for_level(&level_curve, 75), 582_187);
+        assert_eq!(xp_for_level(&level_curve, 100), 1_640_000);
+        assert_eq!(xp_for_level(&level_curve, 101), 1_640_000);
+    }
+
+    #[test]
+    fn level_for_xp_round_trips_xp_for_level() {
+        let level_curves = [
+            LevelCurve::Custom(vec![0, 10, 30, 60]),
+            LevelCurve::Erratic,
+            LevelCurve::Exponential {
+                base: 100.0,
+                growth: 1.1,
+            },
+            LevelCurve::Fluctuating,
+            LevelCurve::Linear {
+                step: 500,
+            },
+            LevelCurve::MediumFast,
+        ];
+
+        for level_curve in level_curves.iter() {
+            for level in 1 ..= max_level(level_curve).min(150) {
+                assert_eq!(level_for_xp(level_curve, xp_for_level(level_curve, level)), level);
+            }
+        }
+    }
+
+    #[test]
+    fn progress_within_level_is_relative_to_level_start() {
+        let level_curve = LevelCurve::Fluctuating;
+
+        assert_eq!(progress_within_level(&level_curve, -10), 0);
+        assert_eq!(progress_within_level(&level_curve, 0), 0);
+        assert_eq!(progress_within_level(&level_curve, 5), 1);
+        assert_eq!(progress_within_level(&level_curve, 1_640_050), 50);
+    }
+
+    #[test]
+    fn xp_to_next_level_is_remaining_xp() {
+        let level_curve = LevelCurve::Fluctuating;
+
+        assert_eq!(xp_to_next_level(&level_curve, -10), Some(4));
+        assert_eq!(xp_to_next_level(&level_curve, 0), Some(4));
+        assert_eq!(xp_to_next_level(&level_curve, 3), Some(1));
+        assert_eq!(xp_to_next_level(&level_curve, 1_571_884), Some(68_116));
+        assert_eq!(xp_to_next_level(&level_curve, 1_640_000), None);
+    }
+
+    #[test]
+    fn unbounded_curves_go_beyond_level_100() {
+        let level_curve = LevelCurve::Linear {
+            step: 1_000,
+        };
+
+        assert_eq!(level_for_xp(&level_curve, 149_999), 150);
+        assert_eq!(level_for_xp(&level_curve, 150_000), 151);
+        assert_eq!(level_for_xp(&LevelCurve::MediumFast, 200i64.pow(3)), 200);
+    }
+
+    #[test]
+    fn custom_curve_is_capped_at_table_length() {
+        let level_curve = LevelCurve::Custom(vec![0, 10, 30, 60]);
+
+        assert_eq!(level_for_xp(&level_curve, 29), 2);
+        assert_eq!(level_for_xp(&level_curve, 1_000), 4);
+        assert_eq!(xp_to_next_level(&level_curve, 1_000), None);
+    }
+
+    #[test]
+    fn member_role_ids_stacks_earned_roles() {
+        let role_ids = HashSet::from([Id::new(1)]);
+
+        assert_eq!(
+            member_role_ids(&role_ids, &levels(), 12, RoleStacking::Stack),
+            HashSet::from([Id::new(1), Id::new(5), Id::new(10)])
+        );
+    }
+
+    #[test]
+    fn member_role_ids_keeps_only_highest_earned_role() {
+        let role_ids = HashSet::from([Id::new(1), Id::new(5), Id::new(20)]);
+
+        assert_eq!(
+            member_role_ids(&role_ids, &levels(), 12, RoleStacking::HighestOnly),
+            HashSet::from([Id::new(1), Id::new(10)])
+        );
+        assert_eq!(
+            member_role_ids(&role_ids, &levels(), 1, RoleStacking::HighestOnly),
+            HashSet::from([Id::new(1)])
+        );
+    }
+}
@@ -3,3 +3,7 @@ pub mod decimal;
 pub mod error;
 pub mod gateway;
 pub mod image;
+pub mod level_up;
+pub mod leveling;
+pub mod season;
+pub mod voice;
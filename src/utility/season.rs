@@ -0,0 +1,35 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::warn;
+
+use crate::{types::context::Context, utility::constants::SEASON_CHECK_MINUTES};
+
+pub async fn close_expired_seasons(context: Arc<Context>) {
+    let mut check_interval = interval(Duration::from_secs(SEASON_CHECK_MINUTES * 60));
+
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        check_interval.tick().await;
+
+        let seasons = match context.database.get_expired_seasons().await {
+            Ok(seasons) => seasons,
+            Err(error) => {
+                warn!(?error, "unable to fetch expired seasons");
+
+                continue;
+            }
+        };
+
+        for (guild_id, season_number) in seasons {
+            if let Err(error) = context
+                .database
+                .end_season(guild_id, season_number, true)
+                .await
+            {
+                warn!(?error, %guild_id, season_number, "unable to end expired season");
+            }
+        }
+    }
+}
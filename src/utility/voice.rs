@@ -0,0 +1,278 @@
+use std::{sync::Arc, time::Duration};
+
+use time::OffsetDateTime;
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::warn;
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{ChannelMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{
+    types::{
+        cache::{Channel, Guild, Member, MemberUpdate, VoiceStatus},
+        context::Context,
+        database::XpSource,
+        Result,
+    },
+    utility::{
+        constants::VOICE_CHECKPOINT_MINUTES,
+        level_up::announce_level_up,
+        leveling::{level_for_xp, member_role_ids},
+    },
+};
+
+pub async fn checkpoint_voice_sessions(context: Arc<Context>) {
+    let mut checkpoint_interval = interval(Duration::from_secs(VOICE_CHECKPOINT_MINUTES * 60));
+
+    checkpoint_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    checkpoint_interval.tick().await;
+
+    loop {
+        checkpoint_interval.tick().await;
+
+        let now = OffsetDateTime::now_utc();
+        let channels = context
+            .cache
+            .channels
+            .read()
+            .values()
+            .filter(|channel| !channel.user_ids.read().is_empty())
+            .cloned()
+            .collect::<Vec<Arc<Channel>>>();
+
+        for channel in channels {
+            let Some(guild) = context.cache.get_guild(channel.guild_id) else {
+                continue;
+            };
+            let user_ids = channel.user_ids.read().clone();
+
+            for user_id in user_ids {
+                if let Err(error) =
+                    credit_voice_xp(&context, &guild, channel.channel_id, user_id, now).await
+                {
+                    warn!(
+                        ?error,
+                        channel_id = %channel.channel_id,
+                        %user_id,
+                        "unable to credit voice xp"
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub async fn credit_voice_xp(
+    context: &Context,
+    guild: &Guild,
+    channel_id: Id<ChannelMarker>,
+    user_id: Id<UserMarker>,
+    now: OffsetDateTime,
+) -> Result<()> {
+    let guild_id = guild.guild_id;
+    let Some(member) = context.cache.get_member(guild_id, user_id) else {
+        return Ok(())
+    };
+
+    if member.bot {
+        return Ok(());
+    }
+
+    let Some(joined_voice_timestamp) =
+        context.cache.advance_joined_voice_timestamp(guild_id, user_id, now)
+    else {
+        return Ok(())
+    };
+    let elapsed_seconds = (now.unix_timestamp() - joined_voice_timestamp.unix_timestamp()).max(0);
+    let xp = if guild.ignored_voice_channel_ids.read().contains(&channel_id)
+        || !member
+            .role_ids
+            .read()
+            .is_disjoint(&guild.ignored_role_ids.read())
+    {
+        0
+    } else {
+        let xp_multiplier = guild.effective_xp_multiplier(channel_id, &member.role_ids.read());
+        let xp_per_minute = *guild.voice_xp_per_minute.read() as f64
+            * voice_rate_multiplier(context, guild, channel_id, *member.voice_status.read());
+        let xp = ((elapsed_seconds as f64) * xp_per_minute / 60.0 * xp_multiplier).floor() as i64;
+        let max_session_xp = *guild.voice_max_session_xp.read();
+
+        if max_session_xp.gt(&0) {
+            xp.min(max_session_xp - *member.voice_session_xp.read()).max(0)
+        } else {
+            xp
+        }
+    };
+    let daily_xp_cap = *guild.voice_daily_xp_cap.read();
+    let xp = if daily_xp_cap.gt(&0) && xp.gt(&0) {
+        let daily_xp = context
+            .database
+            .get_member_daily_voice_xp(guild_id, user_id)
+            .await?;
+
+        xp.min(daily_xp_cap - daily_xp).max(0)
+    } else {
+        xp
+    };
+
+    if xp.le(&0) {
+        return Ok(());
+    }
+
+    let updated_xp = context
+        .database
+        .increment_member_xp(guild_id, user_id, xp, None)
+        .await?;
+    let level_curve = guild.level_curve.read().clone();
+    let current_level = level_for_xp(&level_curve, updated_xp - xp);
+    let updated_level = level_for_xp(&level_curve, updated_xp);
+
+    context
+        .database
+        .add_member_daily_voice_xp(guild_id, user_id, xp)
+        .await?;
+    context
+        .database
+        .insert_xp_history(guild_id, user_id, xp, XpSource::Voice)
+        .await?;
+    context
+        .database
+        .insert_season_xp(guild_id, user_id, xp)
+        .await?;
+    context.cache.update_member(
+        guild_id,
+        user_id,
+        MemberUpdate {
+            voice_session_xp: Some(*member.voice_session_xp.read() + xp),
+            xp: Some(updated_xp),
+            ..Default::default()
+        },
+    );
+
+    if updated_level.ne(&current_level) {
+        let member_role_ids = member_role_ids(
+            &member.role_ids.read(),
+            &guild.levels.read(),
+            updated_level,
+            *guild.role_stacking.read(),
+        );
+
+        context
+            .http
+            .update_guild_member(guild_id, user_id)
+            .roles(&member_role_ids.into_iter().collect::<Vec<Id<RoleMarker>>>())
+            .await?;
+        announce_level_up(context, guild, channel_id, user_id, updated_level, updated_xp).await?;
+    }
+
+    Ok(())
+}
+
+pub fn refresh_voice_channel(
+    context: &Context,
+    guild: &Guild,
+    channel_id: Id<ChannelMarker>,
+    now: OffsetDateTime,
+) {
+    let Some(channel) = context.cache.get_channel(channel_id) else {
+        return
+    };
+    let members = channel
+        .user_ids
+        .read()
+        .iter()
+        .filter_map(|user_id| context.cache.get_member(guild.guild_id, *user_id))
+        .collect::<Vec<Arc<Member>>>();
+    let listener_count = members
+        .iter()
+        .filter(|member| !member.bot && !member.voice_status.read().deafened)
+        .count();
+
+    for member in members {
+        let joined_voice_timestamp =
+            if is_accruing(guild, channel_id, &member, listener_count) {
+                Some(member.joined_voice_timestamp.read().unwrap_or(now))
+            } else {
+                None
+            };
+
+        context.cache.update_member(
+            guild.guild_id,
+            member.user_id,
+            MemberUpdate {
+                joined_voice_timestamp: Some(joined_voice_timestamp),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+pub async fn settle_voice_channel(
+    context: &Context,
+    guild: &Guild,
+    channel_id: Id<ChannelMarker>,
+    now: OffsetDateTime,
+) {
+    let Some(channel) = context.cache.get_channel(channel_id) else {
+        return
+    };
+    let user_ids = channel.user_ids.read().clone();
+
+    for user_id in user_ids {
+        if let Err(error) = credit_voice_xp(context, guild, channel_id, user_id, now).await {
+            warn!(?error, %channel_id, %user_id, "unable to credit voice xp");
+        }
+    }
+}
+
+fn is_accruing(
+    guild: &Guild,
+    channel_id: Id<ChannelMarker>,
+    member: &Member,
+    listener_count: usize,
+) -> bool {
+    if member.bot {
+        return false;
+    }
+
+    if guild
+        .afk_channel_id
+        .read()
+        .map_or(false, |afk_channel_id| afk_channel_id.eq(&channel_id))
+    {
+        return false;
+    }
+
+    let voice_status = *member.voice_status.read();
+
+    if voice_status.deafened || (voice_status.muted && !*guild.voice_allow_muted.read()) {
+        return false;
+    }
+
+    (listener_count as i64).ge(&*guild.voice_min_members.read())
+}
+
+fn voice_rate_multiplier(
+    context: &Context,
+    guild: &Guild,
+    channel_id: Id<ChannelMarker>,
+    voice_status: VoiceStatus,
+) -> f64 {
+    let stage = context
+        .cache
+        .get_channel(channel_id)
+        .map_or(false, |channel| channel.kind.eq(&ChannelType::GuildStageVoice));
+
+    if voice_status.suppressed {
+        *guild.voice_audience_multiplier.read()
+    } else if stage || voice_status.streaming || voice_status.video {
+        *guild.voice_bonus_multiplier.read()
+    } else {
+        1.0
+    }
+}